@@ -0,0 +1,60 @@
+//! Compares the early-exit folds of `third_sync::List` with `Iter` and with walking the `List` through `tail`,
+//! which clones an `Arc` (an atomic increment and decrement) for every element.
+//! Run with `cargo bench --bench fold`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_too_many_linked_lists::third_sync::List;
+
+const LEN: u64 = 10_000;
+
+// the persistent API alone: every step takes a new reference to the rest of the `List`
+fn any_by_tail(list: &List<u64>, f: impl Fn(&u64) -> bool) -> bool {
+    let mut cur = list.clone();
+    while let Some(elem) = cur.head() {
+        if f(elem) {
+            return true;
+        }
+        cur = cur.tail();
+    }
+    false
+}
+
+fn third_sync_fold(c: &mut Criterion) {
+    let list = (0..LEN).fold(List::new(), |list, i| list.prepend(i));
+    // the match is the last element, so every variant walks the whole `List`
+    let target = 0;
+
+    let mut group = c.benchmark_group("third_sync::List");
+    group.bench_function("any", |b| {
+        b.iter(|| black_box(&list).any(|&elem| elem == black_box(target)))
+    });
+    group.bench_function("any by iter", |b| {
+        b.iter(|| {
+            black_box(&list)
+                .iter()
+                .any(|&elem| elem == black_box(target))
+        })
+    });
+    group.bench_function("any by tail", |b| {
+        b.iter(|| any_by_tail(black_box(&list), |&elem| elem == black_box(target)))
+    });
+    group.bench_function("position", |b| {
+        b.iter(|| black_box(&list).position(|&elem| elem == black_box(target)))
+    });
+    group.bench_function("position by iter", |b| {
+        b.iter(|| {
+            black_box(&list)
+                .iter()
+                .position(|&elem| elem == black_box(target))
+        })
+    });
+    group.bench_function("fold", |b| {
+        b.iter(|| black_box(&list).fold(0u64, |acc, &elem| acc ^ elem))
+    });
+    group.bench_function("fold by iter", |b| {
+        b.iter(|| black_box(&list).iter().fold(0u64, |acc, &elem| acc ^ elem))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, third_sync_fold);
+criterion_main!(benches);
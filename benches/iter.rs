@@ -0,0 +1,38 @@
+//! Compares the `Iterator` fast paths of `fifth::Iter` with walking the same `List` one `next` at a time.
+//! Run with `cargo bench --bench iter`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_too_many_linked_lists::fifth::List;
+use std::iter;
+
+const LEN: usize = 10_000;
+
+// hides every method but `next`, so the default implementations are used
+fn by_next<I: Iterator>(mut inner: I) -> impl Iterator<Item = I::Item> {
+    iter::from_fn(move || inner.next())
+}
+
+fn fifth_iter(c: &mut Criterion) {
+    let list: List<u64> = (0..LEN as u64).map(|i| i * 7919 % 10_007).collect();
+
+    let mut group = c.benchmark_group("fifth::Iter");
+    group.bench_function("count", |b| b.iter(|| black_box(&list).iter().count()));
+    group.bench_function("count by next", |b| {
+        b.iter(|| by_next(black_box(&list).iter()).count())
+    });
+    group.bench_function("last", |b| b.iter(|| black_box(&list).iter().last()));
+    group.bench_function("last by next", |b| {
+        b.iter(|| by_next(black_box(&list).iter()).last())
+    });
+    group.bench_function("max", |b| b.iter(|| black_box(&list).iter().max()));
+    group.bench_function("max by next", |b| {
+        b.iter(|| by_next(black_box(&list).iter()).max())
+    });
+    group.bench_function("min", |b| b.iter(|| black_box(&list).iter().min()));
+    group.bench_function("min by next", |b| {
+        b.iter(|| by_next(black_box(&list).iter()).min())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, fifth_iter);
+criterion_main!(benches);
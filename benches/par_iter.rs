@@ -0,0 +1,42 @@
+//! Compares `third_sync::List::par_iter` with the sequential `iter`, once with a trivial sum, where the
+//! walking done by `split` is most visible, and once with some work per element, where it is paid back.
+//! Run with `cargo bench --features rayon --bench par_iter`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rayon::prelude::*;
+use rust_too_many_linked_lists::third_sync::List;
+
+const LEN: u64 = 100_000;
+
+// a few hundred rounds of an integer hash, so each element costs about as much as a small computation
+fn work(&elem: &u64) -> u64 {
+    (0..256).fold(elem, |acc, _| {
+        acc.wrapping_mul(0x9e37_79b9_7f4a_7c15).rotate_left(17)
+    })
+}
+
+fn third_sync_par_iter(c: &mut Criterion) {
+    let list = (0..LEN).fold(List::new(), |list, i| list.prepend(i));
+
+    let mut group = c.benchmark_group("third_sync::List");
+    group.bench_function("sum by iter", |b| {
+        b.iter(|| black_box(&list).iter().sum::<u64>())
+    });
+    group.bench_function("sum by par_iter", |b| {
+        b.iter(|| black_box(&list).par_iter().sum::<u64>())
+    });
+    group.bench_function("work by iter", |b| {
+        b.iter(|| black_box(&list).iter().map(work).fold(0, u64::wrapping_add))
+    });
+    group.bench_function("work by par_iter", |b| {
+        b.iter(|| {
+            black_box(&list)
+                .par_iter()
+                .map(work)
+                .reduce(|| 0, u64::wrapping_add)
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, third_sync_par_iter);
+criterion_main!(benches);
@@ -1,14 +1,26 @@
-use std::ptr;
+use crate::kmerge::{Heap, Source};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::NonNull;
 
 pub struct List<T> {
     head: Link<T>,
     // pointer to the end of the list (queue)
-    tail: *mut Node<T>, // DANGER: raw pointer
+    tail: Link<T>,
+    // number of `Node`s, kept up to date by `push_node` and `pop_node`
+    len: usize,
+    // the `List` owns `T`s, even though it only holds pointers to them, see `Link` below
+    _marker: PhantomData<T>,
 }
 
-// it is inadvisable to mix raw and 'safe' pointer types (like `Box`),
-// so we'll use unsafe pointers everywhere, which can be `null`, so `Option` is not necessary
-type Link<T> = *mut Node<T>;
+// it is inadvisable to mix raw and 'safe' pointer types (like `Box`), so we'll use unsafe pointers everywhere.
+// `NonNull` is a `*mut` that is never null, so the `Option` around it makes the empty case explicit again,
+// and still takes up the same space, as `None` is stored as null.
+// unlike `*mut T`, `NonNull<T>` is covariant, so a `List<&'static str>` can be used as a `List<&'a str>`,
+// just like a `Vec` or `Box` would be. `PhantomData<T>` tells the drop checker that dropping a `List<T>`
+// drops `T`s as well, which a raw pointer alone would not say.
+type Link<T> = Option<NonNull<Node<T>>>;
 
 struct Node<T> {
     elem: T,
@@ -18,20 +30,29 @@ struct Node<T> {
 impl<T> List<T> {
     pub fn new() -> Self {
         List {
-            head: ptr::null_mut(),
-            tail: ptr::null_mut(),
+            head: None,
+            tail: None,
+            len: 0,
+            _marker: PhantomData,
         }
     }
 
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
     pub fn iter(&self) -> Iter<'_, T> {
         unsafe {
             Iter {
-                // `unsafe` function of converting an unsafe pointer to an `Option` of reference
-                next: self.head.as_ref(),
+                // `unsafe` function of converting a `NonNull` to a reference, which has to point to a live `Node`
+                next: self.head.map(|node| node.as_ref()),
+                // the `tail` is kept around so `last` does not have to walk the whole `List`
+                tail: self.tail.map(|node| node.as_ref()),
+                len: self.len,
             }
         }
     }
@@ -39,66 +60,213 @@ impl<T> List<T> {
     pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         unsafe {
             IterMut {
-                // `unsafe` function of converting an unsafe pointer to an `Option` of a mutable reference
-                next: self.head.as_mut(),
+                // `unsafe` function of converting a `NonNull` to a mutable reference
+                next: self.head.map(|mut node| node.as_mut()),
+                len: self.len,
             }
         }
     }
 
     pub fn push(&mut self, elem: T) {
-        unsafe {
-            // use a `Box` to create a pointer, then turn it into an unsafe one
-            // with `into_raw` - the returned pointer has to be freed by us!
-            let new_tail = Box::into_raw(Box::new(Node {
+        // use a `Box` to create a pointer, then turn it into an unsafe one
+        // with `into_raw` - the returned pointer has to be freed by us!
+        // `Box` is never null, so `new_unchecked` is fine here
+        let new_tail = unsafe {
+            NonNull::new_unchecked(Box::into_raw(Box::new(Node {
                 elem,
-                next: ptr::null_mut(), // when pushed onto the `tail`, the next is always `null`
-            }));
-
-            // `is_null` checks for null, equivalent to checking for `None`
-            if !self.tail.is_null() {
-                // dereferencing raw pointers must be put in an `unsafe` block,
-                // other pointer operations (assignments, null-checks) are safe.
-                // if the `tail` existed, update it to point to the `new_tail`
-                (*self.tail).next = new_tail;
-            } else {
-                // otherwise, update the `head` to point to it
-                self.head = new_tail;
-            }
+                next: None, // when pushed onto the `tail`, the next is always `None`
+            })))
+        };
+        self.push_node(new_tail);
+    }
 
-            self.tail = new_tail;
+    /// Adds an element to the front, so the queue can be used as a stack as well
+    pub fn push_front(&mut self, elem: T) {
+        let new_head = unsafe {
+            NonNull::new_unchecked(Box::into_raw(Box::new(Node {
+                elem,
+                next: self.head,
+            })))
+        };
+        // pushing into an empty `List` creates its only `Node`, which is the `tail` as well
+        if self.tail.is_none() {
+            self.tail = Some(new_head);
         }
+        self.head = Some(new_head);
+        self.len += 1;
     }
 
     pub fn pop(&mut self) -> Option<T> {
-        unsafe {
-            if self.head.is_null() {
-                None
-            } else {
-                // convert a raw pointer to a `Box`, so it is `drop`ped automatically
-                let head = Box::from_raw(self.head);
-                self.head = head.next;
+        // convert the raw pointer to a `Box`, so it is `drop`ped automatically
+        self.pop_node()
+            .map(|head| unsafe { Box::from_raw(head.as_ptr()) }.elem)
+    }
 
-                if self.head.is_null() {
-                    self.tail = ptr::null_mut();
-                }
+    /// Pops the first element only if `predicate` returns `true` for it, like `Vec::pop_if` does for the last one.
+    /// The predicate gets `&mut T`, so it can also update an element that is not ready yet.
+    pub fn pop_if(&mut self, predicate: impl FnOnce(&mut T) -> bool) -> Option<T> {
+        if predicate(self.peek_mut()?) {
+            self.pop()
+        } else {
+            None
+        }
+    }
 
-                Some(head.elem)
+    /// Keeps only the elements `f` returns `true` for, in order. Removed `Node`s are unlinked and freed on the way,
+    /// and the `head` and `tail` follow when the ends are removed.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        // the last `Node` that was kept, the next kept one has to be linked after it
+        let mut prev: Link<T> = None;
+        let mut cur = self.head;
+        while let Some(node) = cur {
+            unsafe {
+                cur = (*node.as_ptr()).next;
+                if f(&(*node.as_ptr()).elem) {
+                    prev = Some(node);
+                    continue;
+                }
+                // skip over `node`, then it is not reachable from the `List` anymore
+                match prev {
+                    Some(prev) => (*prev.as_ptr()).next = cur,
+                    None => self.head = cur,
+                }
+                if cur.is_none() {
+                    self.tail = prev;
+                }
+                self.len -= 1;
+                // everything is relinked before freeing, so the `List` is consistent even if dropping `T` panics
+                drop(Box::from_raw(node.as_ptr()));
             }
         }
     }
 
-    pub fn peek(&self) -> Option<&T> {
-        unsafe {
-            // `unsafe` function of converting an unsafe pointer to an `Option` of reference
-            self.head.as_ref().map(|node| &node.elem)
+    /// Moves every element of `other` to the end of this `List` in O(1), by linking its `head` after our `tail`.
+    /// `other` is left empty.
+    pub fn append(&mut self, other: &mut List<T>) {
+        let Some(other_head) = other.head.take() else {
+            return;
+        };
+        match self.tail {
+            Some(tail) => unsafe { (*tail.as_ptr()).next = Some(other_head) },
+            None => self.head = Some(other_head),
+        }
+        // `other` is not empty, so it has a `tail`, which is ours now
+        self.tail = other.tail.take();
+        self.len += mem::take(&mut other.len);
+    }
+
+    /// Splits the `List` in two at the given index, returning everything from `at` onwards as a new `List`.
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        assert!(at <= self.len, "Cannot split off at a nonexistent index");
+        if at == 0 {
+            // everything goes, and this `List` becomes empty, `tail` included
+            return mem::take(self);
         }
+
+        // walk to the last `Node` that stays, `at > 0` means there is one
+        let mut last = self.head.unwrap();
+        for _ in 1..at {
+            last = unsafe { (*last.as_ptr()).next.unwrap() };
+        }
+        let head = unsafe { (*last.as_ptr()).next.take() };
+        let suffix = List {
+            head,
+            // if nothing comes after `last`, the suffix is empty and our `tail` stays
+            tail: if head.is_some() {
+                self.tail.replace(last)
+            } else {
+                None
+            },
+            len: self.len - at,
+            _marker: PhantomData,
+        };
+        self.len = at;
+        suffix
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        unsafe { self.head.map(|node| &(*node.as_ptr()).elem) }
     }
 
     pub fn peek_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.head.map(|node| &mut (*node.as_ptr()).elem) }
+    }
+
+    // unlinks the first `Node` without freeing it, the caller becomes responsible for the allocation
+    fn pop_node(&mut self) -> Link<T> {
+        let head = self.head?;
         unsafe {
-            // `unsafe` function of converting an unsafe pointer to an `Option` of a mutable reference
-            self.head.as_mut().map(|node| &mut node.elem)
+            // dereferencing raw pointers must be put in an `unsafe` block,
+            // other pointer operations (assignments, comparisons) are safe.
+            self.head = (*head.as_ptr()).next.take();
+        }
+        if self.head.is_none() {
+            self.tail = None;
+        }
+        self.len -= 1;
+        Some(head)
+    }
+
+    // links a detached `Node` to the end, taking over the responsibility of freeing it
+    fn push_node(&mut self, node: NonNull<Node<T>>) {
+        match self.tail {
+            // if the `tail` existed, update it to point to the new `Node`
+            Some(tail) => unsafe { (*tail.as_ptr()).next = Some(node) },
+            // otherwise, update the `head` to point to it
+            None => self.head = Some(node),
         }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+}
+
+// raw pointers (`NonNull` included) are neither `Send` nor `Sync`, so the compiler cannot derive these.
+// SAFETY: the `List` owns its `Node`s exclusively, just like a `Box` would: no pointer to a `Node` is shared with
+// anything outside the `List`, so moving the `List` to another thread moves the `T`s with it (`T: Send`),
+// and a `&List` only ever hands out `&T`s, so sharing it is fine if sharing `&T` is (`T: Sync`).
+// `&mut` access, which would need more, always goes through `&mut List`, which the borrow checker keeps unique.
+unsafe impl<T: Send> Send for List<T> {}
+unsafe impl<T: Sync> Sync for List<T> {}
+
+/// Prints the elements front to back like a slice, the pointers are an implementation detail
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Element-wise equality. The lengths are known, so `List`s of different sizes are rejected without walking them.
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+/// Compares with a slice front to back, so tests can check the whole `List` without draining it
+impl<T: PartialEq> PartialEq<[T]> for List<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for List<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for List<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -106,18 +274,47 @@ impl<T> Drop for List<T> {
     fn drop(&mut self) {
         // go through the `List` and `pop` each element, which `drop`s all `Box`es
         // that have been created from `self.head`
-        while let Some(_) = self.pop() {}
+        while self.pop().is_some() {}
+    }
+}
+
+// `push` links onto the `tail` directly, so every element is O(1), without walking the `List`
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
     }
 }
 
 pub struct IntoIter<T>(List<T>);
 
+// the `Iterator`s count down the elements left, so they know their exact size
 pub struct Iter<'a, T> {
     next: Option<&'a Node<T>>,
+    tail: Option<&'a Node<T>>,
+    len: usize,
 }
 
 pub struct IterMut<'a, T> {
     next: Option<&'a mut Node<T>>,
+    len: usize,
 }
 
 impl<T> Iterator for IntoIter<T> {
@@ -125,18 +322,76 @@ impl<T> Iterator for IntoIter<T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.0.pop()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
 }
 
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
     fn next(&mut self) -> Option<Self::Item> {
         unsafe {
             self.next.map(|node| {
-                self.next = node.next.as_ref();
+                self.next = node.next.map(|next| next.as_ref());
+                self.len -= 1;
                 &node.elem
             })
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    // the default `last` calls `next` until the `Iterator` is exhausted, which is O(n),
+    // but as long as there is anything left to yield, the last element is always the `tail`
+    fn last(self) -> Option<Self::Item> {
+        self.next.and(self.tail).map(|node| &node.elem)
+    }
+
+    // the number of elements left is tracked anyway, so there is nothing to walk
+    fn count(self) -> usize {
+        self.len
+    }
+
+    // walking the `Node`s in a plain loop, instead of going through `next` and its `Option` for each element,
+    // `sum`, `for_each` and the like all end up here
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        let mut cur = self.next;
+        while let Some(node) = cur {
+            acc = f(acc, &node.elem);
+            cur = unsafe { node.next.map(|next| next.as_ref()) };
+        }
+        acc
+    }
+
+    // same result as the default: the last one of equal maximums, and the first one of equal minimums
+    fn max(self) -> Option<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        self.fold(None, |max, elem| match max {
+            Some(max) if max > elem => Some(max),
+            _ => Some(elem),
+        })
+    }
+
+    fn min(self) -> Option<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        self.fold(None, |min, elem| match min {
+            Some(min) if min <= elem => Some(min),
+            _ => Some(elem),
+        })
+    }
 }
 
 impl<'a, T> Iterator for IterMut<'a, T> {
@@ -144,15 +399,66 @@ impl<'a, T> Iterator for IterMut<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         unsafe {
             self.next.take().map(|node| {
-                self.next = node.next.as_mut();
+                self.next = node.next.map(|mut next| next.as_mut());
+                self.len -= 1;
                 &mut node.elem
             })
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Merges any number of sorted `List`s into a single sorted sequence, using a `BinaryHeap` that always
+/// has the `List` with the smallest front on top. Equal elements keep the order of the `List`s they came from.
+pub fn kmerge<T: Ord>(lists: Vec<List<T>>) -> KMerge<T> {
+    KMerge(Heap::new(lists))
 }
 
+/// Same as `kmerge`, but relinks the `Node`s onto the `tail` of a new `List` instead of yielding the elements
+pub fn kmerge_into_list<T: Ord>(lists: Vec<List<T>>) -> List<T> {
+    let mut merge = kmerge(lists);
+    let mut list = List::new();
+    while let Some(node) = merge.0.pop_with(List::pop_node) {
+        list.push_node(node);
+    }
+    list
+}
+
+pub struct KMerge<T>(Heap<List<T>>);
+
+impl<T: Ord> Iterator for KMerge<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        // the detached `Node` is owned by nobody, so turn it back into a `Box` to free it
+        self.0
+            .pop_with(List::pop_node)
+            .map(|node| unsafe { Box::from_raw(node.as_ptr()) }.elem)
+    }
+}
+
+impl<T: Ord> Source for List<T> {
+    type Elem = T;
+
+    fn head(&self) -> Option<&T> {
+        self.peek()
+    }
+}
+
+#[cfg(test)]
 mod test {
-    use super::List;
+    use super::{kmerge, kmerge_into_list, List};
+    use std::thread;
 
     #[test]
     fn basics() {
@@ -192,6 +498,508 @@ mod test {
         assert_eq!(list.pop(), None);
     }
 
+    #[test]
+    fn send_sync() {
+        fn is_send<T: Send>() {}
+        fn is_sync<T: Sync>() {}
+
+        is_send::<List<i32>>();
+        is_sync::<List<i32>>();
+        is_send::<super::IntoIter<String>>();
+        // `Cell` is not `Sync`, but it is `Send`, which is all that moving the `List` needs
+        is_send::<List<std::cell::Cell<i32>>>();
+    }
+
+    #[test]
+    fn move_to_thread() {
+        let mut list = List::new();
+        for elem in 0..100 {
+            list.push(elem);
+        }
+
+        let mut list = thread::spawn(move || {
+            // the other thread can keep using the queue, then hand it back
+            assert_eq!(list.pop(), Some(0));
+            list.push(100);
+            list
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(list.iter().sum::<i32>(), (1..=100).sum::<i32>());
+        assert_eq!(list.pop(), Some(1));
+
+        // `Sync`: several threads can read the same `List` at once
+        thread::scope(|s| {
+            s.spawn(|| assert_eq!(list.peek(), Some(&2)));
+            s.spawn(|| assert_eq!(list.iter().last(), Some(&100)));
+        });
+    }
+
+    #[test]
+    fn covariant() {
+        // this only compiles if `List` is covariant in `T`, like the standard collections
+        fn shorten<'a>(list: List<&'static str>) -> List<&'a str> {
+            list
+        }
+
+        // `local` has to outlive the `List`, as `PhantomData<T>` makes dropping the `List` count as using its `T`s
+        let local = String::from("local");
+        let mut list = List::new();
+        list.push("static");
+        let mut list = shorten(list);
+        list.push(&local);
+        assert_eq!(list.pop(), Some("static"));
+        assert_eq!(list.pop(), Some("local"));
+    }
+
+    #[test]
+    fn push_front() {
+        let mut list = List::new();
+        list.push_front(1);
+        assert_eq!(list.iter().last(), Some(&1));
+
+        // `push` has to find the `tail` set by `push_front`
+        list.push(2);
+        list.push_front(0);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(list.iter().last(), Some(&2));
+
+        // used as a stack
+        assert_eq!(list.pop(), Some(0));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), None);
+
+        // after emptying, the `tail` is reset, so `push_front` sets it again
+        list.push_front(3);
+        list.push(4);
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn append() {
+        let mut a = List::new();
+        let mut b = List::new();
+
+        // empty into empty
+        a.append(&mut b);
+        assert!(a.is_empty());
+
+        // non-empty into empty
+        b.push(1);
+        b.push(2);
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_eq!(b.pop(), None);
+        assert_eq!(a.len(), 2);
+
+        // empty into non-empty
+        a.append(&mut b);
+        assert_eq!(a.len(), 2);
+
+        // non-empty into non-empty
+        b.push(3);
+        b.push(4);
+        a.append(&mut b);
+        assert_eq!(a.len(), 4);
+        assert_eq!(a.iter().last(), Some(&4));
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        // both `List`s still work: the `tail` of `a` moved, and `b` has none
+        a.push(5);
+        b.push(6);
+        assert_eq!(a.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(b.iter().last(), Some(&6));
+    }
+
+    #[test]
+    fn split_off() {
+        fn collect(list: &List<i32>) -> Vec<i32> {
+            list.iter().copied().collect()
+        }
+        fn from(elems: &[i32]) -> List<i32> {
+            let mut list = List::new();
+            for &elem in elems {
+                list.push(elem);
+            }
+            list
+        }
+
+        // in the middle
+        let mut list = from(&[1, 2, 3, 4]);
+        let mut suffix = list.split_off(2);
+        assert_eq!(collect(&list), vec![1, 2]);
+        assert_eq!(collect(&suffix), vec![3, 4]);
+        assert_eq!((list.len(), suffix.len()), (2, 2));
+        // both `tail`s have to be right for `push` to work
+        list.push(5);
+        suffix.push(6);
+        assert_eq!(collect(&list), vec![1, 2, 5]);
+        assert_eq!(collect(&suffix), vec![3, 4, 6]);
+
+        // at 0, the original becomes empty
+        let mut list = from(&[1, 2]);
+        let suffix = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(list.iter().last(), None);
+        assert_eq!(collect(&suffix), vec![1, 2]);
+        list.push(7);
+        assert_eq!(collect(&list), vec![7]);
+
+        // at `len`, the suffix is empty
+        let mut list = from(&[1, 2]);
+        let mut suffix = list.split_off(2);
+        assert!(suffix.is_empty());
+        list.push(3);
+        suffix.push(4);
+        assert_eq!(collect(&list), vec![1, 2, 3]);
+        assert_eq!(collect(&suffix), vec![4]);
+
+        // at 1 of a single element
+        let mut list = from(&[1]);
+        assert!(list.split_off(1).is_empty());
+        assert_eq!(list.iter().last(), Some(&1));
+
+        let mut list: List<i32> = List::new();
+        assert!(list.split_off(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_off_out_of_bounds() {
+        let mut list = List::new();
+        list.push(1);
+        list.split_off(2);
+    }
+
+    #[test]
+    fn from_iter_extend() {
+        let mut list: List<_> = (1..=3).collect();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().last(), Some(&3));
+
+        list.extend(vec![4, 5]);
+        list.extend(std::iter::empty());
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+        let mut list: List<i32> = std::iter::empty().collect();
+        assert!(list.is_empty());
+        list.extend([1]);
+        assert_eq!(list.pop(), Some(1));
+    }
+
+    #[test]
+    fn debug() {
+        let mut list = List::new();
+        assert_eq!(format!("{list:?}"), "[]");
+        list.push("b");
+        list.push_front("a");
+        assert_eq!(format!("{list:?}"), r#"["a", "b"]"#);
+        assert_eq!(format!("{list:#?}"), "[\n    \"a\",\n    \"b\",\n]");
+    }
+
+    #[test]
+    fn eq() {
+        let mut a = List::new();
+        let mut b = List::new();
+        assert_eq!(a, b);
+        assert_eq!(a, []);
+
+        a.push(1);
+        assert_ne!(a, b);
+        b.push(1);
+        assert_eq!(a, b);
+
+        a.push(2);
+        b.push(3);
+        assert_ne!(a, b);
+        assert_eq!(a, [1, 2]);
+        assert_eq!(a, &[1, 2][..]);
+        assert_eq!(a, vec![1, 2][..]);
+        assert_ne!(a, [1, 2, 3]);
+        assert_ne!(a, [1]);
+        assert_ne!(b, [1, 2]);
+
+        // a shorter `List` that is a prefix is not equal
+        b.pop();
+        b.pop();
+        b.push(1);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn pop_if() {
+        let mut list = List::new();
+        assert_eq!(list.pop_if(|_: &mut i32| true), None);
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.pop_if(|&mut elem| elem > 1), None);
+        assert_eq!(list.pop_if(|&mut elem| elem == 1), Some(1));
+        assert_eq!(list.len(), 2);
+
+        // the predicate can change the element it looks at, e.g. counting down a retry budget
+        assert_eq!(
+            list.pop_if(|elem| {
+                *elem -= 1;
+                *elem == 0
+            }),
+            None
+        );
+        assert_eq!(list, [1, 3]);
+        assert_eq!(
+            list.pop_if(|elem| {
+                *elem -= 1;
+                *elem == 0
+            }),
+            Some(0)
+        );
+
+        // popping the last element resets the `tail`
+        assert_eq!(list.pop_if(|_| true), Some(3));
+        assert!(list.is_empty());
+        list.push(4);
+        assert_eq!(list, [4]);
+    }
+
+    #[test]
+    fn retain() {
+        let mut list: List<i32> = (1..=10).collect();
+        list.retain(|&elem| elem % 2 == 0);
+        assert_eq!(list, [2, 4, 6, 8, 10]);
+        assert_eq!(list.len(), 5);
+
+        // both ends removed, the `tail` has to move back so `push` still works
+        list.retain(|&elem| elem != 2 && elem != 10);
+        assert_eq!(list, [4, 6, 8]);
+        list.push(12);
+        assert_eq!(list, [4, 6, 8, 12]);
+        assert_eq!(list.iter().last(), Some(&12));
+
+        // everything kept
+        list.retain(|_| true);
+        assert_eq!(list, [4, 6, 8, 12]);
+
+        // everything removed
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(list.iter().last(), None);
+        list.push(1);
+        assert_eq!(list, [1]);
+
+        let mut list: List<i32> = List::new();
+        list.retain(|_| false);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn retain_frees_nodes() {
+        use std::rc::Rc;
+
+        let elems: Vec<_> = (0..5).map(Rc::new).collect();
+        let mut list: List<_> = elems.iter().cloned().collect();
+        list.retain(|elem| **elem % 2 == 1);
+        // the removed elements were dropped right away, only the `Vec` holds them now
+        assert_eq!(
+            elems.iter().map(Rc::strong_count).collect::<Vec<_>>(),
+            vec![1, 2, 1, 2, 1]
+        );
+        drop(list);
+        assert!(elems.iter().all(|elem| Rc::strong_count(elem) == 1));
+    }
+
+    #[test]
+    fn retain_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut list: List<i32> = (1..=5).collect();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            list.retain(|&elem| {
+                assert!(elem != 4);
+                elem != 2
+            })
+        }));
+        assert!(result.is_err());
+        // everything before the panic has been processed, the rest is untouched
+        assert_eq!(list, [1, 3, 4, 5]);
+        list.push(6);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().last(), Some(&6));
+    }
+
+    #[test]
+    fn len() {
+        let mut list = List::new();
+        assert!(list.is_empty());
+        assert_eq!(list.iter().len(), 0);
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.len(), 3);
+        assert!(!list.is_empty());
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        iter.next();
+        iter.next();
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+
+        let mut iter = list.iter_mut();
+        iter.next();
+        assert_eq!(iter.len(), 2);
+
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.len(), 2);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.len(), 2);
+        iter.next();
+        assert_eq!(iter.len(), 1);
+
+        // relinking keeps the count right as well
+        let mut a = List::new();
+        let mut b = List::new();
+        a.push(1);
+        b.push(0);
+        b.push(2);
+        let merged = kmerge_into_list(vec![a, b]);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged.iter().len(), 3);
+    }
+
+    #[test]
+    fn iter_fast_paths() {
+        let mut list: List<i32> = [3, 1, 4, 1, 5, 9, 2, 6].into_iter().collect();
+        assert_eq!(list.iter().count(), 8);
+        assert_eq!(list.iter_mut().count(), 8);
+        assert_eq!(list.iter().sum::<i32>(), 31);
+        assert_eq!(list.iter().max(), Some(&9));
+        assert_eq!(list.iter().min(), Some(&1));
+
+        // partially consumed `Iterator`s only look at what is left
+        let partial = || {
+            let mut iter = list.iter();
+            iter.nth(5);
+            iter
+        };
+        assert_eq!(partial().count(), 2);
+        assert_eq!(partial().max(), Some(&6));
+        assert_eq!(partial().min(), Some(&2));
+        let mut iter = list.iter_mut();
+        iter.next();
+        assert_eq!(iter.count(), 7);
+
+        let empty: List<i32> = List::new();
+        assert_eq!(empty.iter().count(), 0);
+        assert_eq!(empty.iter().max(), None);
+        assert_eq!(empty.iter().min(), None);
+    }
+
+    #[test]
+    fn iter_max_min_ties() {
+        let elems =
+            [(1, 'a'), (2, 'b'), (0, 'c'), (2, 'd'), (0, 'e')].map(|(key, tag)| Keyed(key, tag));
+        let list: List<_> = elems.iter().copied().collect();
+        // the same picks as the default implementations on a slice
+        assert_eq!(list.iter().max().unwrap().1, elems.iter().max().unwrap().1);
+        assert_eq!(list.iter().min().unwrap().1, elems.iter().min().unwrap().1);
+        assert_eq!(list.iter().max().unwrap().1, 'd');
+        assert_eq!(list.iter().min().unwrap().1, 'c');
+    }
+
+    /// Compares only by the number
+    #[derive(Clone, Copy)]
+    struct Keyed(i32, char);
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Keyed {}
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn iter_last() {
+        let mut list = List::new();
+        assert_eq!(list.iter().last(), None);
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.iter().last(), Some(&3));
+
+        // partially consumed `Iterator`s still end at the `tail`
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.last(), Some(&3));
+
+        // exhausted `Iterator`s have nothing left, even though the `tail` is still there
+        let mut iter = list.iter();
+        while iter.next().is_some() {}
+        assert_eq!(iter.last(), None);
+    }
+
+    #[test]
+    fn kmerge_lists() {
+        let mut a = List::new();
+        let mut b = List::new();
+        let mut c = List::new();
+        for elem in [1, 4, 7] {
+            a.push(elem);
+        }
+        for elem in [2, 5, 8, 9] {
+            b.push(elem);
+        }
+        for elem in [3, 6] {
+            c.push(elem);
+        }
+
+        let merged: Vec<_> = kmerge(vec![a, List::new(), b, c]).collect();
+        assert_eq!(merged, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn kmerge_into() {
+        let mut a = List::new();
+        let mut b = List::new();
+        a.push(1);
+        a.push(3);
+        b.push(2);
+
+        let mut list = kmerge_into_list(vec![a, b]);
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        // the `tail` has to be right after relinking, so pushing still works
+        list.push(4);
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), None);
+    }
+
     // `miri` is a tool for exploring Undefined Behaviour during runtime, so it can help catching
     // bugs in `unsafe` code
     // to install and run it, execute `cargo +nightly-<version> miri test`
@@ -216,7 +1024,9 @@ mod test {
 
         assert_eq!(list.peek(), Some(&3));
         list.push(6);
-        list.peek_mut().map(|x| *x *= 10);
+        if let Some(x) = list.peek_mut() {
+            *x *= 10;
+        }
         assert_eq!(list.peek(), Some(&30));
         assert_eq!(list.pop(), Some(30));
 
@@ -232,10 +1042,22 @@ mod test {
         assert_eq!(iter.next(), None);
 
         assert_eq!(list.pop(), Some(400));
-        list.peek_mut().map(|x| *x *= 10);
+        if let Some(x) = list.peek_mut() {
+            *x *= 10;
+        }
         assert_eq!(list.peek(), Some(&5000));
         list.push(7);
 
+        // unlinking and freeing from the front, middle and back
+        list.push(8);
+        list.push(9);
+        list.retain(|&elem| elem != 5000 && elem != 7 && elem != 9);
+        assert_eq!(list, [600, 8]);
+        list.push(10);
+        list.retain(|&elem| elem != 10);
+        list.push(11);
+        assert_eq!(list.pop(), Some(600));
+
         // drop it on the ground and let `drop` exercise itself
     }
 }
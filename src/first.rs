@@ -1,8 +1,11 @@
+use std::fmt;
 use std::mem;
 
-/// Declare a `List` type only containing the `head`, so that internal types are not leaked out to users
+/// Declare a `List` type only containing the `head` and the length, so that internal types are not leaked out to users
 pub struct List {
     head: Link,
+    // counting the `Node`s on every `push` and `pop` is cheaper than walking the `List` when asked
+    len: usize,
 }
 
 /// When an `enum` is defined like this, as in one element is empty, while the other has a non-null pointer in it,
@@ -22,7 +25,42 @@ struct Node {
 
 impl List {
     pub fn new() -> Self {
-        Self { head: Link::Empty }
+        Self {
+            head: Link::Empty,
+            len: 0,
+        }
+    }
+
+    /// Creates a `List` that yields the elements in the same order as the slice,
+    /// so they are pushed starting from the end
+    pub fn from_slice(elems: &[i32]) -> Self {
+        let mut list = List::new();
+        list.extend(elems.iter().rev().copied());
+        list
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        // without `Option`, the `Iterator` can simply point at the next `Link` itself
+        Iter { next: &self.head }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
+        IterMut {
+            // a mutable reference cannot be copied out of `&mut Link` and left behind, so `IterMut`
+            // does need an `Option` it can `take` from, see `IterMut::next`
+            next: match &mut self.head {
+                Link::Empty => None,
+                Link::More(node) => Some(node),
+            },
+        }
     }
 
     pub fn push(&mut self, elem: i32) {
@@ -34,6 +72,7 @@ impl List {
         });
         // link up `head` to point to the newly added `Node`
         self.head = Link::More(new_node);
+        self.len += 1;
     }
 
     pub fn pop(&mut self) -> Option<i32> {
@@ -43,10 +82,33 @@ impl List {
             Link::Empty => None,
             Link::More(node) => {
                 self.head = node.next;
+                self.len -= 1;
                 Some(node.elem)
             }
         }
     }
+
+    pub fn peek(&self) -> Option<&i32> {
+        // there are no helpers like `Option::as_ref` for our own `enum`, so match on a reference to `head`,
+        // which makes `node` a reference as well, instead of moving the `Box` out
+        match &self.head {
+            Link::Empty => None,
+            Link::More(node) => Some(&node.elem),
+        }
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut i32> {
+        match &mut self.head {
+            Link::Empty => None,
+            Link::More(node) => Some(&mut node.elem),
+        }
+    }
+}
+
+impl Default for List {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Drop for List {
@@ -66,6 +128,100 @@ impl Drop for List {
     }
 }
 
+/// `#[derive(Debug)]` would print the internal `Link`s and `Node`s, so print the elements like a `Vec` instead
+impl fmt::Debug for List {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Two `List`s are equal if they hold the same elements in the same order
+impl PartialEq for List {
+    fn eq(&self, other: &Self) -> bool {
+        // different lengths can never be equal, so there is no need to walk the `List`s
+        if self.len != other.len {
+            return false;
+        }
+
+        // walk both `List`s in lockstep, matching on the pair of `Link`s
+        let mut left = &self.head;
+        let mut right = &other.head;
+        loop {
+            match (left, right) {
+                (Link::Empty, Link::Empty) => return true,
+                (Link::More(l), Link::More(r)) if l.elem == r.elem => {
+                    left = &l.next;
+                    right = &r.next;
+                }
+                _ => return false,
+            }
+        }
+    }
+}
+
+impl Eq for List {}
+
+/// `extend` works the same as calling `push` for each element, so the last one ends up on top
+impl Extend<i32> for List {
+    fn extend<I: IntoIterator<Item = i32>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+impl IntoIterator for List {
+    type Item = i32;
+    type IntoIter = IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+pub struct IntoIter(List);
+
+impl Iterator for IntoIter {
+    type Item = i32;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+}
+
+pub struct Iter<'a> {
+    next: &'a Link,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a i32;
+    fn next(&mut self) -> Option<Self::Item> {
+        // `self.next` is a shared reference, which is `Copy`, so matching on it does not move anything
+        match self.next {
+            Link::Empty => None,
+            Link::More(node) => {
+                self.next = &node.next;
+                Some(&node.elem)
+            }
+        }
+    }
+}
+
+pub struct IterMut<'a> {
+    next: Option<&'a mut Node>,
+}
+
+impl<'a> Iterator for IterMut<'a> {
+    type Item = &'a mut i32;
+    fn next(&mut self) -> Option<Self::Item> {
+        // move the reference out, so that both the element and the next `Link` can be borrowed for `'a`
+        let node = self.next.take()?;
+        self.next = match &mut node.next {
+            Link::Empty => None,
+            Link::More(next) => Some(next),
+        };
+        Some(&mut node.elem)
+    }
+}
+
 /// This indicates that the `test` module should only be compiled when running tests
 #[cfg(test)]
 mod test {
@@ -100,4 +256,130 @@ mod test {
         assert_eq!(list.pop(), Some(1));
         assert_eq!(list.pop(), None);
     }
+
+    #[test]
+    fn peek() {
+        let mut list = List::new();
+        assert_eq!(list.peek(), None);
+        assert_eq!(list.peek_mut(), None);
+
+        list.push(1);
+        list.push(2);
+        assert_eq!(list.peek(), Some(&2));
+        assert_eq!(list.peek_mut(), Some(&mut 2));
+
+        // test whether the value can actually be mutated
+        if let Some(value) = list.peek_mut() {
+            *value = 42;
+        }
+        assert_eq!(list.peek(), Some(&42));
+        assert_eq!(list.pop(), Some(42));
+        assert_eq!(list.peek(), Some(&1));
+    }
+
+    #[test]
+    fn long_list_drop() {
+        // with the compiler's recursive `Drop`, this would overflow the stack
+        let mut list = List::new();
+        for elem in 0..1_000_000 {
+            list.push(elem);
+        }
+        drop(list);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        for elem in list.iter_mut() {
+            *elem *= 10;
+        }
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.next(), Some(&mut 30));
+        assert_eq!(iter.next(), Some(&mut 20));
+        assert_eq!(iter.next(), Some(&mut 10));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn len() {
+        let mut list = List::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        list.push(1);
+        list.push(2);
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_empty());
+
+        list.pop();
+        list.pop();
+        // popping an empty `List` does not underflow
+        list.pop();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        let mut list = List::from_slice(&[1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop(), Some(1));
+
+        // pushed one after the other, so 5 is on top
+        list.extend([4, 5]);
+        assert_eq!(list.len(), 4);
+        let elems: Vec<_> = list.into_iter().collect();
+        assert_eq!(elems, vec![5, 4, 2, 3]);
+
+        assert!(List::from_slice(&[]).is_empty());
+    }
+
+    #[test]
+    fn debug_eq() {
+        let list = List::from_slice(&[1, 2, 3]);
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+        assert_eq!(format!("{:?}", List::new()), "[]");
+
+        assert_eq!(list, List::from_slice(&[1, 2, 3]));
+        assert_ne!(list, List::from_slice(&[1, 2]));
+        assert_ne!(list, List::from_slice(&[1, 2, 4]));
+        assert_eq!(List::new(), List::new());
+
+        let mut other = List::new();
+        other.extend([3, 2, 1]);
+        assert_eq!(list, other);
+    }
 }
@@ -1,9 +1,17 @@
-use std::cell::{Ref, RefCell, RefMut};
-use std::rc::Rc;
+use std::cell::{BorrowError, BorrowMutError, Ref, RefCell, RefMut};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt;
+use std::mem;
+use std::rc::{Rc, Weak};
 
 pub struct List<T> {
     head: Link<T>,
     tail: Link<T>,
+    // number of `Node`s, updated by the push and pop methods
+    len: usize,
+    // identity of the `List`, shared by all of its `Node`s, so a `NodeHandle` can tell which `List` its `Node` is in
+    id: Rc<()>,
 }
 
 /// `RefCell` is a type that enforces borrowing at runtime. If any rules are broken, it `panic`s.
@@ -17,19 +25,23 @@ struct Node<T> {
     elem: T,
     next: Link<T>,
     prev: Link<T>,
+    // the `id` of the `List` this `Node` is in
+    owner: Rc<()>,
 }
 
 impl<T> Node<T> {
-    fn new(elem: T) -> Rc<RefCell<Self>> {
+    fn new(elem: T, owner: &Rc<()>) -> Rc<RefCell<Self>> {
         Rc::new(RefCell::new(Node {
             elem,
             prev: None,
             next: None,
+            owner: owner.clone(),
         }))
     }
 }
 
 /// Invariant to keep in mind when writing all `List` operations: each `Node` should have exactly 2 pointers to it.
+/// (`fourth_weak` has the same `List` with `Weak` backward pointers, where every `Node` has exactly 1 strong pointer.)
 /// `Node`s in the middle are pointed by their predecessor and successor, while the `Node`s on the end are
 /// pointed by their sole neighbour and the `List` itself.
 /// `List`s having only one element point both their pointers to the sole element.
@@ -38,16 +50,31 @@ impl<T> List<T> {
         List {
             head: None,
             tail: None,
+            len: 0,
+            id: Rc::new(()),
         }
     }
 
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes every element, one `Node` at a time, so the `Rc` cycles are broken without recursion
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
     }
 
     pub fn push_front(&mut self, elem: T) {
+        self.link_front(Node::new(elem, &self.id));
+    }
+
+    // attaches a detached `Node` of this `List` before the current `head`
+    fn link_front(&mut self, new_head: Rc<RefCell<Node<T>>>) {
         // new `Node` needs +2 links, while everything else should be +0
-        let new_head = Node::new(elem);
         match self.head.take() {
             Some(old_head) => {
                 // non-empty `List`, need to connect `old_head` to `new_head` and vice-versa
@@ -64,10 +91,11 @@ impl<T> List<T> {
                                             // total: +2 `new_head`
             }
         }
+        self.len += 1;
     }
 
     pub fn push_back(&mut self, elem: T) {
-        let new_tail = Node::new(elem);
+        let new_tail = Node::new(elem, &self.id);
         match self.tail.take() {
             Some(old_tail) => {
                 old_tail.borrow_mut().next = Some(new_tail.clone());
@@ -79,6 +107,7 @@ impl<T> List<T> {
                 self.tail = Some(new_tail);
             }
         }
+        self.len += 1;
     }
 
     pub fn pop_front(&mut self) -> Option<T> {
@@ -99,6 +128,7 @@ impl<T> List<T> {
                                       // total: -2 `old_head`, +0 `new_head`
                 }
             }
+            self.len -= 1;
             // `try_unwrap` is required so the underlying `Refcell<Node<T>>` is moved out of the pointer,
             // this should always succeed, as the program is written correctly, i.e. the variable named `old_head`
             // is the last one referencing the data held by this `Rc`, so it can safely unwrap it.
@@ -123,6 +153,7 @@ impl<T> List<T> {
                     self.head.take();
                 }
             }
+            self.len -= 1;
             Rc::try_unwrap(old_tail).ok().unwrap().into_inner().elem
         })
     }
@@ -130,35 +161,705 @@ impl<T> List<T> {
     /// `RefCell`s return a type called `Ref` when calling `borrow`, which keeps track of when the current borrow
     /// should be `drop`ped, this function cannot return `Option<&T>`, as the resulting `Ref` coming from `borrow` would
     /// get `drop`ped inside this function, invalidating the underlying shared reference.
-    /// Instead, an `Option<Ref<T>>` can be returned, which can be dereferenced the same ways a a `&T`, as
+    /// Instead, an `Option<Ref<'_, T>>` can be returned, which can be dereferenced the same ways a a `&T`, as
     /// it also implements the `Deref` trait. For this, the function `Ref::map` can be used, which creates a new
     /// `Ref` instance that holds the value defined by a mapping function it requires, which can extract data
     /// from the passed in `Ref<T>`, converting it to `Ref<U>`, which is connected to the same `RefCell` as
     /// the original `Ref<T>`, which is exactly what is needed in this case.
-    pub fn peek_front(&self) -> Option<Ref<T>> {
+    pub fn peek_front(&self) -> Option<Ref<'_, T>> {
         self.head
             .as_ref()
             // create a `borrow` for the underlying `Node`, and map it so only the `elem` is visible to the caller
             .map(|node| Ref::map(node.borrow(), |node| &node.elem))
     }
 
-    pub fn peek_back(&self) -> Option<Ref<T>> {
+    pub fn peek_back(&self) -> Option<Ref<'_, T>> {
         self.tail
             .as_ref()
             .map(|node| Ref::map(node.borrow(), |node| &node.elem))
     }
 
-    pub fn peek_back_mut(&mut self) -> Option<RefMut<T>> {
+    pub fn peek_back_mut(&mut self) -> Option<RefMut<'_, T>> {
         self.tail
             .as_ref()
             .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
     }
 
-    pub fn peek_front_mut(&mut self) -> Option<RefMut<T>> {
+    pub fn peek_front_mut(&mut self) -> Option<RefMut<'_, T>> {
         self.head
             .as_ref()
             .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
     }
+
+    /// Runs `f` on the first element and returns its result. The `RefMut` lives only for the duration of the call,
+    /// so the caller never has to think about when it is dropped.
+    pub fn update_front<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        self.peek_front_mut().map(|mut elem| f(&mut elem))
+    }
+
+    pub fn update_back<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        self.peek_back_mut().map(|mut elem| f(&mut elem))
+    }
+
+    /// Splits the `List` in two at the given index, returning everything from `at` onwards as a new `List`.
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        assert!(at <= self.len, "Cannot split off at a nonexistent index");
+        if at == 0 {
+            return mem::take(self);
+        }
+        if at == self.len {
+            return List::new();
+        }
+
+        // the first `Node` of the suffix
+        let new_head = self.node_at(at).unwrap();
+
+        // sever the links in both directions: -1 `new_tail`, -1 `new_head`
+        let new_tail = new_head.borrow_mut().prev.take().unwrap();
+        new_tail.borrow_mut().next.take();
+
+        let mut suffix = List {
+            // +1 `new_head`, which is the reference taken while walking
+            head: Some(new_head),
+            // the old `tail` keeps its count, it just belongs to the other `List` now
+            tail: self.tail.replace(new_tail), // +1 `new_tail`
+            len: self.len - at,
+            id: Rc::new(()),
+        };
+        self.len = at;
+        // every `Node` still has our `id` as its owner, so the shorter part gets the new one,
+        // which keeps this O(min(at, len - at)), the same as walking to `at`
+        if suffix.len > self.len {
+            mem::swap(&mut self.id, &mut suffix.id);
+            self.retag();
+        } else {
+            suffix.retag();
+        }
+        suffix
+    }
+
+    /// Points the `owner` of every `Node` to this `List`'s `id`
+    fn retag(&self) {
+        let mut cur = self.head.clone();
+        while let Some(node) = cur {
+            let mut node = node.borrow_mut();
+            node.owner = self.id.clone();
+            cur = node.next.clone();
+        }
+    }
+
+    /// Moves the first element to the back by relinking its `Node`, without moving `T` or allocating
+    pub fn move_front_to_back(&mut self) {
+        if self.len < 2 {
+            return;
+        }
+        // detach `old_head`: -1 (`head`), -1 (`new_head.prev`)
+        let old_head = self.head.take().unwrap();
+        let new_head = old_head.borrow_mut().next.take().unwrap(); // -1 `new_head`
+        new_head.borrow_mut().prev = None;
+        self.head = Some(new_head); // +1 `new_head`
+
+        // attach it after `old_tail`: +1 (`old_tail.next`), +1 (`tail`)
+        let old_tail = self.tail.take().unwrap(); // -1 `old_tail`
+        old_tail.borrow_mut().next = Some(old_head.clone());
+        old_head.borrow_mut().prev = Some(old_tail); // +1 `old_tail`
+        self.tail = Some(old_head);
+    }
+
+    /// Moves the last element to the front by relinking its `Node`, without moving `T` or allocating
+    pub fn move_back_to_front(&mut self) {
+        if self.len < 2 {
+            return;
+        }
+        let old_tail = self.tail.take().unwrap();
+        let new_tail = old_tail.borrow_mut().prev.take().unwrap();
+        new_tail.borrow_mut().next = None;
+        self.tail = Some(new_tail);
+
+        let old_head = self.head.take().unwrap();
+        old_head.borrow_mut().prev = Some(old_tail.clone());
+        old_tail.borrow_mut().next = Some(old_head);
+        self.head = Some(old_tail);
+    }
+
+    /// Reverses the `List` in place by swapping the `prev` and `next` pointers of every `Node`, then `head` and `tail`.
+    /// Pointers only change places, so every reference count stays the same.
+    pub fn reverse(&mut self) {
+        let mut cur = self.head.clone();
+        while let Some(node) = cur {
+            // the `borrow_mut` is limited to this block, so it is released before moving on to the next `Node`
+            cur = {
+                let mut node = node.borrow_mut();
+                let node = &mut *node;
+                mem::swap(&mut node.prev, &mut node.next);
+                // after the swap, the old `next` is in `prev`
+                node.prev.clone()
+            };
+        }
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Stable merge sort that relinks the `Node`s instead of swapping the elements, so `NodeHandle`s keep
+    /// pointing to the same elements. While sorting, the `List` is treated as a singly linked one:
+    /// every `prev` is cleared first, and then repaired in a single pass at the end.
+    /// If `compare` `panic`s, every element is kept, but their order is unspecified, like with `slice::sort_by`.
+    pub fn sort_by(&mut self, mut compare: impl FnMut(&T, &T) -> Ordering) {
+        if self.len < 2 {
+            return;
+        }
+        // the guard holds an extra pointer to every `Node`, so they stay alive while the `List` is taken apart,
+        // and relinks them in the order of `nodes` when it is dropped, even if `compare` `panic`s
+        let mut guard = SortGuard {
+            nodes: Vec::with_capacity(self.len),
+            list: self,
+        };
+        guard.list.tail = None;
+        let mut cur = guard.list.head.clone();
+        while let Some(node) = cur {
+            {
+                let mut node = node.borrow_mut();
+                node.prev = None;
+                cur = node.next.clone();
+            }
+            guard.nodes.push(node);
+        }
+
+        let head = Self::merge_sort(
+            guard.list.head.take().unwrap(),
+            guard.list.len,
+            &mut compare,
+        );
+
+        // sorting went through, so the guard relinks the `Node`s in the sorted order instead
+        guard.nodes.clear();
+        let mut cur = Some(head);
+        while let Some(node) = cur {
+            cur = node.borrow().next.clone();
+            guard.nodes.push(node);
+        }
+    }
+
+    /// Non-panicking version of `peek_front`: returns an `Err` instead if the `Node` is currently borrowed mutably.
+    /// Through the `List`'s own methods this cannot happen, as every `RefMut` they hand out borrows the `List`
+    /// mutably, but it can when `Node`s are reached some other way, e.g. from code holding on to a `RefMut`
+    /// it got while the `List` was part of a bigger `RefCell`-based structure.
+    pub fn try_peek_front(&self) -> Result<Option<Ref<'_, T>>, BorrowError> {
+        self.head
+            .as_ref()
+            .map(|node| {
+                node.try_borrow()
+                    .map(|node| Ref::map(node, |node| &node.elem))
+            })
+            // `Option<Result>` to `Result<Option>`, so `?` can be used on the result
+            .transpose()
+    }
+
+    pub fn try_peek_back(&self) -> Result<Option<Ref<'_, T>>, BorrowError> {
+        self.tail
+            .as_ref()
+            .map(|node| {
+                node.try_borrow()
+                    .map(|node| Ref::map(node, |node| &node.elem))
+            })
+            .transpose()
+    }
+
+    pub fn try_peek_front_mut(&mut self) -> Result<Option<RefMut<'_, T>>, BorrowMutError> {
+        self.head
+            .as_ref()
+            .map(|node| {
+                node.try_borrow_mut()
+                    .map(|node| RefMut::map(node, |node| &mut node.elem))
+            })
+            .transpose()
+    }
+
+    pub fn try_peek_back_mut(&mut self) -> Result<Option<RefMut<'_, T>>, BorrowMutError> {
+        self.tail
+            .as_ref()
+            .map(|node| {
+                node.try_borrow_mut()
+                    .map(|node| RefMut::map(node, |node| &mut node.elem))
+            })
+            .transpose()
+    }
+
+    /// Calls `f` with the element at index `n` and returns its result, or `None` if out of bounds.
+    /// The `Ref` only lives inside this function, so, unlike with `peek_front`, there is no guard to return.
+    pub fn with_nth<R>(&self, n: usize, f: impl FnOnce(&T) -> R) -> Option<R> {
+        self.node_at(n).map(|node| f(&node.borrow().elem))
+    }
+
+    /// Same as `with_nth`, but `f` can change the element
+    pub fn with_nth_mut<R>(&mut self, n: usize, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        self.node_at(n).map(|node| f(&mut node.borrow_mut().elem))
+    }
+
+    /// Same as `push_front`, but returns a handle that can later remove this element in O(1) using `remove`
+    pub fn push_front_handle(&mut self, elem: T) -> NodeHandle<T> {
+        self.push_front(elem);
+        NodeHandle(Rc::downgrade(self.head.as_ref().unwrap()))
+    }
+
+    /// Same as `push_back`, but returns a handle that can later remove this element in O(1) using `remove`
+    pub fn push_back_handle(&mut self, elem: T) -> NodeHandle<T> {
+        self.push_back(elem);
+        NodeHandle(Rc::downgrade(self.tail.as_ref().unwrap()))
+    }
+
+    /// Returns a handle observing the first `Node`, which can tell whether it has been popped since
+    pub fn downgrade_front(&self) -> Option<NodeHandle<T>> {
+        self.head
+            .as_ref()
+            .map(|node| NodeHandle(Rc::downgrade(node)))
+    }
+
+    pub fn downgrade_back(&self) -> Option<NodeHandle<T>> {
+        self.tail
+            .as_ref()
+            .map(|node| NodeHandle(Rc::downgrade(node)))
+    }
+
+    /// Removes the element the handle points to, or returns `None` if it is not in this `List`:
+    /// it was removed or popped already, or its `Node` is in another `List`. Every `Node` knows the `id` of its
+    /// `List`, so this is an O(1) pointer comparison.
+    pub fn remove(&mut self, handle: &NodeHandle<T>) -> Option<T> {
+        let node = self.own_node(handle)?;
+        self.unlink(&node);
+        Some(Rc::try_unwrap(node).ok().unwrap().into_inner().elem)
+    }
+
+    /// Moves the element the handle points to to the front in O(1), by relinking its `Node`,
+    /// so nothing is allocated and the handle stays valid. Returns `false` if it is not in this `List`.
+    pub fn move_to_front(&mut self, handle: &NodeHandle<T>) -> bool {
+        let Some(node) = self.own_node(handle) else {
+            return false;
+        };
+        self.unlink(&node);
+        self.link_front(node);
+        true
+    }
+
+    // the `Node` behind the handle, if it is alive and in this `List`
+    fn own_node(&self, handle: &NodeHandle<T>) -> Link<T> {
+        let node = handle.0.upgrade()?;
+        // `&mut self` in the callers rules out an outstanding borrow of one of our own `Node`s,
+        // so if the `Node` is borrowed mutably right now, it belongs to another `List`
+        let is_ours = node
+            .try_borrow()
+            .is_ok_and(|node| Rc::ptr_eq(&node.owner, &self.id));
+        is_ours.then_some(node)
+    }
+
+    /// Unlinks `node` from its neighbours (or the ends of the `List`), returning the `Node` that came after it.
+    /// Afterwards, the only pointers left to `node` are the ones held by the caller.
+    fn unlink(&mut self, node: &Rc<RefCell<Node<T>>>) -> Link<T> {
+        // -1 `prev` and -1 `next`, as `node` no longer points to them
+        let (prev, next) = {
+            let mut node = node.borrow_mut();
+            (node.prev.take(), node.next.take())
+        };
+        // -2 `node`, as the pointers to it from both sides get overwritten
+        match &prev {
+            Some(prev) => prev.borrow_mut().next = next.clone(), // +1 `next`
+            None => self.head = next.clone(),
+        }
+        match &next {
+            Some(next) => next.borrow_mut().prev = prev.clone(), // +1 `prev`
+            None => self.tail = prev,
+        }
+        self.len -= 1;
+        next
+    }
+
+    /// Walks the whole `List` in both directions and `panic`s if any of its invariants is broken:
+    /// - every `Node` has exactly 2 strong pointers to it (its neighbours, or the `List` at the ends)
+    /// - the `prev` of each `Node` points to the one before it, and `head` and `tail` point to the ends
+    /// - every `Node` has this `List`'s `id` as its owner
+    /// - walking backwards from `tail` visits the same `Node`s as walking forwards from `head`, and there are `len` of them
+    ///
+    /// This is meant for tests and debugging: it is O(n), and every `Node` is borrowed, so it `panic`s if one is
+    /// borrowed mutably somewhere else.
+    pub fn debug_validate(&self) {
+        let mut forward = Vec::with_capacity(self.len);
+        let mut prev: Link<T> = None;
+        let mut cur = self.head.clone();
+        while let Some(node) = cur {
+            // the ends have 2 strong pointers too, not 1: `head` stands in for the missing `prev` of the first `Node`,
+            // and `tail` for the missing `next` of the last one, and a single `Node` is held by both `head` and `tail`.
+            // -1 for the local `node`
+            assert_eq!(
+                Rc::strong_count(&node) - 1,
+                2,
+                "Node {} does not have exactly 2 strong pointers",
+                forward.len()
+            );
+            let node_ref = node.borrow();
+            let prev_matches = match (&prev, &node_ref.prev) {
+                (Some(expected), Some(actual)) => Rc::ptr_eq(expected, actual),
+                (None, None) => true,
+                _ => false,
+            };
+            assert!(
+                prev_matches,
+                "Node {} has a broken prev link",
+                forward.len()
+            );
+            assert!(
+                Rc::ptr_eq(&node_ref.owner, &self.id),
+                "Node {} belongs to another List",
+                forward.len()
+            );
+            assert!(
+                forward.len() < self.len,
+                "The List has more Nodes than its len"
+            );
+            forward.push(Rc::as_ptr(&node));
+            cur = node_ref.next.clone();
+            drop(node_ref);
+            prev = Some(node);
+        }
+        assert_eq!(
+            forward.len(),
+            self.len,
+            "The List has fewer Nodes than its len"
+        );
+        let tail_matches = match (&prev, &self.tail) {
+            (Some(last), Some(tail)) => Rc::ptr_eq(last, tail),
+            (None, None) => true,
+            _ => false,
+        };
+        assert!(tail_matches, "tail does not point to the last Node");
+
+        // `prev` links were checked pairwise above, but walking them confirms nothing else is reachable backwards
+        let mut cur = self.tail.clone();
+        for expected in forward.iter().rev() {
+            let node = cur.expect("The List has fewer Nodes backwards than forwards");
+            assert!(
+                std::ptr::eq(Rc::as_ptr(&node), *expected),
+                "Walking backwards visits a different Node"
+            );
+            cur = node.borrow().prev.clone();
+        }
+        assert!(
+            cur.is_none(),
+            "The List has more Nodes backwards than forwards"
+        );
+    }
+
+    /// Returns a new pointer to the `Node` at index `n`, walking from whichever end is closer
+    fn node_at(&self, n: usize) -> Link<T> {
+        if n >= self.len {
+            return None;
+        }
+        if n <= self.len / 2 {
+            let mut cur = self.head.clone()?;
+            for _ in 0..n {
+                let next = cur.borrow().next.clone()?;
+                cur = next;
+            }
+            Some(cur)
+        } else {
+            let mut cur = self.tail.clone()?;
+            for _ in n..self.len - 1 {
+                let prev = cur.borrow().prev.clone()?;
+                cur = prev;
+            }
+            Some(cur)
+        }
+    }
+
+    /// Sorts the chain of `len` `Node`s starting at `head`, following only the `next` pointers
+    fn merge_sort(
+        head: Rc<RefCell<Node<T>>>,
+        len: usize,
+        compare: &mut impl FnMut(&T, &T) -> Ordering,
+    ) -> Rc<RefCell<Node<T>>> {
+        if len < 2 {
+            return head;
+        }
+        // cut the chain after its first half
+        let mid = len / 2;
+        let mut last = head.clone();
+        for _ in 1..mid {
+            let next = last.borrow().next.clone().unwrap();
+            last = next;
+        }
+        let second = last.borrow_mut().next.take().unwrap();
+        drop(last);
+
+        let first = Self::merge_sort(head, mid, compare);
+        let second = Self::merge_sort(second, len - mid, compare);
+        Self::merge(first, second, compare)
+    }
+
+    /// Merges two sorted, non-empty chains. On ties, the `Node` from `left` goes first, which makes the sort stable.
+    fn merge(
+        left: Rc<RefCell<Node<T>>>,
+        right: Rc<RefCell<Node<T>>>,
+        compare: &mut impl FnMut(&T, &T) -> Ordering,
+    ) -> Rc<RefCell<Node<T>>> {
+        let (mut left, mut right) = (Some(left), Some(right));
+        let mut head: Link<T> = None;
+        let mut last: Link<T> = None;
+        while let (Some(l), Some(r)) = (&left, &right) {
+            let take_right = compare(&r.borrow().elem, &l.borrow().elem).is_lt();
+            let side = if take_right { &mut right } else { &mut left };
+            let node = side.take().unwrap();
+            *side = node.borrow_mut().next.take();
+            match &last {
+                Some(last) => last.borrow_mut().next = Some(node.clone()),
+                None => head = Some(node.clone()),
+            }
+            last = Some(node);
+        }
+        // one side ran out, the rest of the other one is already linked together
+        last.unwrap().borrow_mut().next = left.or(right);
+        head.unwrap()
+    }
+
+    /// Returns an `Iterator` yielding a `Ref` guard for each element, front to back.
+    /// The hard part is that reading a `Node`'s `next` pointer requires borrowing that `Node`,
+    /// but the next `Node` has to outlive that short borrow. See `Iter::next` for why it does.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// Same as `iter`, but yields `RefMut` guards. Every `Node` is borrowed mutably exactly once,
+    /// so holding on to several guards at once is fine.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// Returns a cursor pointing at the first element, which can move around and edit the `List` in the middle
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head.clone(),
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// Returns a cursor pointing at the last element
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.tail.clone(),
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    /// Calls `f` on every element, front to back. Unlike `iter`, this needs no `unsafe`: each `Node` is only borrowed
+    /// for the duration of the call, and the walk holds its own `Rc` to the next `Node` instead of a reference.
+    pub fn for_each_ref(&self, mut f: impl FnMut(&T)) {
+        let mut cur = self.head.clone();
+        while let Some(node) = cur {
+            let node = node.borrow();
+            f(&node.elem);
+            cur = node.next.clone();
+        }
+    }
+
+    /// Same as `for_each_ref`, but `f` can change the elements
+    pub fn for_each_mut(&mut self, mut f: impl FnMut(&mut T)) {
+        let mut cur = self.head.clone();
+        while let Some(node) = cur {
+            let mut node = node.borrow_mut();
+            f(&mut node.elem);
+            cur = node.next.clone();
+        }
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, unlinking the rest wherever they are
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let mut cursor = self.cursor_front_mut();
+        while let Some(keep) = cursor.current().map(|elem| f(&elem)) {
+            if keep {
+                cursor.move_next();
+            } else {
+                // moves to the next element by itself
+                cursor.remove_current();
+            }
+        }
+    }
+
+    /// Removes the first element for which `f` returns `true`, and returns it
+    pub fn remove_first_where(&mut self, mut f: impl FnMut(&T) -> bool) -> Option<T> {
+        let mut cursor = self.cursor_front_mut();
+        while let Some(found) = cursor.current().map(|elem| f(&elem)) {
+            if found {
+                return cursor.remove_current();
+            }
+            cursor.move_next();
+        }
+        None
+    }
+
+    /// Returns the first `Some` returned by `f`, which sees one element at a time.
+    /// Every `Ref` is released before moving on, and the result is returned by value, so no borrow escapes.
+    pub fn find_map<R>(&self, mut f: impl FnMut(&T) -> Option<R>) -> Option<R> {
+        self.iter().find_map(|elem| f(&elem))
+    }
+
+    /// Moves the elements into a `Vec`, front to back, freeing the `Node`s on the way
+    pub fn into_vec(self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend(self);
+        vec
+    }
+}
+
+impl<T: Clone> List<T> {
+    /// Clones the first element while it is borrowed, so no `Ref` is handed out and nothing needs to be dropped
+    pub fn peek_front_cloned(&self) -> Option<T> {
+        self.peek_front().map(|elem| elem.clone())
+    }
+
+    pub fn peek_back_cloned(&self) -> Option<T> {
+        self.peek_back().map(|elem| elem.clone())
+    }
+}
+
+impl<T: PartialEq> List<T> {
+    pub fn contains(&self, elem: &T) -> bool {
+        self.iter().any(|other| *other == *elem)
+    }
+}
+
+impl<T: Ord> List<T> {
+    /// Inserts `elem` before the first greater element, so a sorted `List` stays sorted.
+    /// Equal elements are skipped, so the new one ends up after them.
+    pub fn insert_sorted(&mut self, elem: T) {
+        let mut cursor = self.cursor_front_mut();
+        while cursor.current().is_some_and(|current| *current <= elem) {
+            cursor.move_next();
+        }
+        // at the ghost position (every element is smaller or the `List` is empty), this is a `push_back`
+        cursor.insert_before(elem);
+    }
+
+    pub fn sort(&mut self) {
+        self.sort_by(T::cmp);
+    }
+}
+
+/// Used by `sort_by` to put the `List` back together, whether sorting finished or `panic`ked halfway through.
+/// Every `prev` and `next` is overwritten, so whatever chains were left by the merge sort do not matter, and as
+/// `nodes` keeps each `Node` alive, dropping those chains during unwinding never recurses.
+struct SortGuard<'a, T> {
+    list: &'a mut List<T>,
+    nodes: Vec<Rc<RefCell<Node<T>>>>,
+}
+
+impl<T> Drop for SortGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut prev: Link<T> = None;
+        for node in &self.nodes {
+            {
+                let mut node = node.borrow_mut();
+                node.prev = prev.clone(); // +1 `prev`
+                node.next = None;
+            }
+            if let Some(prev) = &prev {
+                prev.borrow_mut().next = Some(node.clone()); // +1 `node`
+            }
+            prev = Some(node.clone());
+        }
+        // +1 for the first and the last `Node`, so with `nodes` gone, every `Node` has 2 pointers again
+        self.list.head = self.nodes.first().cloned();
+        self.list.tail = prev;
+        self.list.len = self.nodes.len();
+    }
+}
+
+/// Extends the lifetime of a pointer to the next `Node` to the lifetime of the `List`'s borrow.
+///
+/// # Safety
+/// The `List` owning the `Node` has to be borrowed for `'a`. Every `Node` of a `List` is kept alive by its
+/// neighbours or the `List` itself, and links only change through `&mut List` methods, which cannot be called
+/// while the `List` is borrowed, so the `Node` lives at least as long as the borrow.
+unsafe fn extend<'a, T>(link: &Link<T>) -> Option<&'a RefCell<Node<T>>> {
+    link.as_ref().map(|node| unsafe { &*Rc::as_ptr(node) })
+}
+
+/// Prints the elements front to back, like a slice. A `Node` that is mutably borrowed somewhere else cannot be read,
+/// not even its `next` pointer, so instead of `panic`king, it is printed as `<borrowed>`, and the rest of the `List`
+/// is reached from the back. If that walk gets stuck on a borrowed `Node` too, the ones in between are printed as `..`.
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+
+        let mut printed = 0;
+        let mut cur = self.head.clone();
+        let blocked = loop {
+            let Some(node) = cur else {
+                return list.finish();
+            };
+            let Ok(borrowed) = node.try_borrow() else {
+                break node;
+            };
+            list.entry(&borrowed.elem);
+            printed += 1;
+            cur = borrowed.next.clone();
+        };
+        list.entry(&format_args!("<borrowed>"));
+        printed += 1;
+
+        // walk backwards until reaching the borrowed `Node` or getting stuck on another one
+        let mut back = Vec::new();
+        let mut stuck = false;
+        let mut cur = self.tail.clone();
+        while let Some(node) = cur.filter(|node| !Rc::ptr_eq(node, &blocked)) {
+            let Ok(borrowed) = node.try_borrow() else {
+                stuck = true;
+                break;
+            };
+            cur = borrowed.prev.clone();
+            drop(borrowed);
+            back.push(node);
+        }
+        if stuck {
+            if self.len > printed + back.len() + 1 {
+                list.entry(&format_args!(".."));
+            }
+            list.entry(&format_args!("<borrowed>"));
+        }
+        for node in back.iter().rev() {
+            list.entry(&node.borrow().elem);
+        }
+        list.finish()
+    }
+}
+
+/// Deep copy: every `Node` is recreated, so the two `List`s share nothing and can be changed independently
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        let mut list = List::new();
+        for elem in self.iter() {
+            list.push_back(elem.clone());
+        }
+        list
+    }
+}
+
+/// Element-wise equality, walking both `List`s with shared borrows, so neither is changed
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().zip(other.iter()).all(|(a, b)| *a == *b)
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> Drop for List<T> {
@@ -167,7 +868,43 @@ impl<T> Drop for List<T> {
         // the `Node` the appropriate reference counts decrement, which eventually lead to the whole
         // `List` get freed appropriately. This implementation is important, as otherwise,
         // the reference counts of `Rc`s would be stuck at 1 because they would be pointing at each other
-        while self.pop_front().is_some() {}
+        self.clear();
+    }
+}
+
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> From<Vec<T>> for List<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for List<T> {
+    fn from(deque: VecDeque<T>) -> Self {
+        deque.into_iter().collect()
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
     }
 }
 
@@ -190,47 +927,227 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::List;
+/// Points to a `Node` without keeping it alive, so the `List`'s invariant of exactly 2 strong pointers per `Node`
+/// still holds while handles exist. Once the element is removed or popped, the handle just stops working.
+pub struct NodeHandle<T>(Weak<RefCell<Node<T>>>);
 
-    #[test]
-    fn basics() {
-        let mut list = List::new();
+impl<T> NodeHandle<T> {
+    /// Whether the `Node` still exists. Removing or popping an element frees its `Node` right away, as the `List`
+    /// is the only owner, so this turns `false` once the element is taken out. Moving the `Node` to another `List`,
+    /// e.g. with `split_off`, keeps it alive, and the handle can be used with that `List` afterwards.
+    pub fn is_alive(&self) -> bool {
+        self.0.strong_count() > 0
+    }
 
-        // check empty list behaves right
-        assert_eq!(list.pop_front(), None);
+    /// Number of strong pointers to the `Node`, 0 once it is gone. While it is in a `List`, this is always 2,
+    /// which makes the invariant of the `List` observable from the outside.
+    pub fn strong_count(&self) -> usize {
+        self.0.strong_count()
+    }
+}
 
-        // populate list
-        list.push_front(1);
-        list.push_front(2);
-        list.push_front(3);
+impl<T: Clone> NodeHandle<T> {
+    /// Clones the element if the `Node` is still alive. `panic`s if it is currently borrowed mutably,
+    /// e.g. through a `RefMut` returned by `peek_front_mut`.
+    pub fn upgrade_value(&self) -> Option<T> {
+        self.0.upgrade().map(|node| node.borrow().elem.clone())
+    }
+}
 
-        // check normal removal
-        assert_eq!(list.pop_front(), Some(3));
-        assert_eq!(list.pop_front(), Some(2));
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        NodeHandle(self.0.clone())
+    }
+}
 
-        // push some more just to make sure nothing is corrupted
-        list.push_front(4);
-        list.push_front(5);
+/// A cursor over the `List`, like `std::collections::linked_list::CursorMut`. Besides the elements, there is a
+/// "ghost" position between the last and the first one (which is the only position of an empty `List`),
+/// so moving past either end and moving back again always works.
+/// The cursor holds an extra `Rc` to the current `Node`, on top of the usual 2, so this has to be let go
+/// before a `Node` can be unwrapped.
+pub struct CursorMut<'a, T> {
+    list: &'a mut List<T>,
+    // `None` at the ghost position
+    current: Link<T>,
+    // `list.len` at the ghost position
+    index: usize,
+}
 
-        // check normal removal
-        assert_eq!(list.pop_front(), Some(5));
-        assert_eq!(list.pop_front(), Some(4));
+impl<T> CursorMut<'_, T> {
+    /// Returns the index of the current element, or `None` at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.as_ref().map(|_| self.index)
+    }
 
-        // check exhaustion
-        assert_eq!(list.pop_front(), Some(1));
-        assert_eq!(list.pop_front(), None);
+    pub fn current(&mut self) -> Option<RefMut<'_, T>> {
+        self.current
+            .as_ref()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
 
-        // ---- back -----
+    /// Moves to the next element, from the last one to the ghost position, and from there to the first one
+    pub fn move_next(&mut self) {
+        match self.current.take() {
+            Some(node) => {
+                self.current = node.borrow().next.clone();
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head.clone();
+                self.index = 0;
+            }
+        }
+    }
 
-        // check empty list behaves right
-        assert_eq!(list.pop_back(), None);
+    /// Moves to the previous element, from the first one to the ghost position, and from there to the last one
+    pub fn move_prev(&mut self) {
+        match self.current.take() {
+            Some(node) => {
+                self.current = node.borrow().prev.clone();
+                self.index = match self.current {
+                    Some(_) => self.index - 1,
+                    None => self.list.len,
+                };
+            }
+            None => {
+                self.current = self.list.tail.clone();
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
 
-        // populate list
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
+    /// Inserts a new element after the current one, or at the front at the ghost position
+    pub fn insert_after(&mut self, elem: T) {
+        let Some(current) = &self.current else {
+            self.list.push_front(elem);
+            // the ghost position is always at `len`
+            self.index += 1;
+            return;
+        };
+        let next = current.borrow().next.clone();
+        match next {
+            Some(next) => {
+                // +2 `new`, +1 -1 `current` (`new.prev` instead of `next.prev`), +1 -1 `next` (`new.next` instead of `current.next`)
+                let new = Node::new(elem, &self.list.id);
+                new.borrow_mut().prev = Some(current.clone());
+                new.borrow_mut().next = Some(next.clone());
+                next.borrow_mut().prev = Some(new.clone());
+                current.borrow_mut().next = Some(new);
+                self.list.len += 1;
+            }
+            None => self.list.push_back(elem),
+        }
+    }
+
+    /// Inserts a new element before the current one, or at the back at the ghost position
+    pub fn insert_before(&mut self, elem: T) {
+        let Some(current) = &self.current else {
+            self.list.push_back(elem);
+            self.index += 1;
+            return;
+        };
+        let prev = current.borrow().prev.clone();
+        match prev {
+            Some(prev) => {
+                let new = Node::new(elem, &self.list.id);
+                new.borrow_mut().next = Some(current.clone());
+                new.borrow_mut().prev = Some(prev.clone());
+                prev.borrow_mut().next = Some(new.clone());
+                current.borrow_mut().prev = Some(new);
+                self.list.len += 1;
+            }
+            None => self.list.push_front(elem),
+        }
+        // the current element moved one place further
+        self.index += 1;
+    }
+
+    /// Removes the current element and moves to the next one, or returns `None` at the ghost position
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current.take()?;
+        // the index stays the same, which is `len` again if the last element was removed
+        self.current = self.list.unlink(&node);
+        // only the local `node` is left pointing to the `Node`
+        Some(Rc::try_unwrap(node).ok().unwrap().into_inner().elem)
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a RefCell<Node<T>>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = Ref<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            let node = node.borrow();
+            // SAFETY: `Iter` is created from `&'a List`, so the `List` stays borrowed for `'a`
+            self.next = unsafe { extend(&node.next) };
+            Ref::map(node, |node| &node.elem)
+        })
+    }
+}
+
+pub struct IterMut<'a, T> {
+    next: Option<&'a RefCell<Node<T>>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = RefMut<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            let node = node.borrow_mut();
+            // SAFETY: `IterMut` is created from `&'a mut List`, so the `List` stays borrowed for `'a`
+            self.next = unsafe { extend(&node.next) };
+            RefMut::map(node, |node| &mut node.elem)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::List;
+    use std::collections::VecDeque;
+    use std::mem::{self, ManuallyDrop};
+    use std::rc::Rc;
+
+    #[test]
+    fn basics() {
+        let mut list = List::new();
+
+        // check empty list behaves right
+        assert_eq!(list.pop_front(), None);
+
+        // populate list
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        // check normal removal
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(2));
+
+        // push some more just to make sure nothing is corrupted
+        list.push_front(4);
+        list.push_front(5);
+
+        // check normal removal
+        assert_eq!(list.pop_front(), Some(5));
+        assert_eq!(list.pop_front(), Some(4));
+
+        // check exhaustion
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), None);
+
+        // ---- back -----
+
+        // check empty list behaves right
+        assert_eq!(list.pop_back(), None);
+
+        // populate list
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
 
         // check normal removal
         assert_eq!(list.pop_back(), Some(3));
@@ -249,6 +1166,848 @@ mod test {
         assert_eq!(list.pop_back(), None);
     }
 
+    #[test]
+    fn len_clear() {
+        let mut list = List::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        list.push_front(1);
+        list.push_back(2);
+        list.push_front(3);
+        assert_eq!(list.len(), 3);
+        assert!(!list.is_empty());
+
+        list.pop_back();
+        assert_eq!(list.len(), 2);
+        list.pop_front();
+        list.pop_front();
+        list.pop_front();
+        assert_eq!(list.len(), 0);
+
+        for i in 0..100_000 {
+            list.push_back(i);
+        }
+        assert_eq!(list.len(), 100_000);
+        list.clear();
+        assert!(list.is_empty());
+        assert!(list.peek_front().is_none());
+        assert!(list.peek_back().is_none());
+
+        // still usable after clearing
+        list.push_back(1);
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn debug() {
+        let mut list = List::new();
+        assert_eq!(format!("{list:?}"), "[]");
+
+        for i in 1..=5 {
+            list.push_back(i);
+        }
+        assert_eq!(format!("{list:?}"), "[1, 2, 3, 4, 5]");
+
+        let mut nodes = Vec::new();
+        let mut cur = list.head.clone();
+        while let Some(node) = cur {
+            cur = node.borrow().next.clone();
+            nodes.push(node);
+        }
+
+        {
+            let _guard = nodes[0].borrow_mut();
+            assert_eq!(format!("{list:?}"), "[<borrowed>, 2, 3, 4, 5]");
+        }
+        {
+            let _guard = nodes[2].borrow_mut();
+            assert_eq!(format!("{list:?}"), "[1, 2, <borrowed>, 4, 5]");
+            // shared borrows do not get in the way
+            let _other = nodes[3].borrow();
+            assert_eq!(format!("{list:?}"), "[1, 2, <borrowed>, 4, 5]");
+        }
+        {
+            let _first = nodes[1].borrow_mut();
+            let _second = nodes[2].borrow_mut();
+            assert_eq!(format!("{list:?}"), "[1, <borrowed>, <borrowed>, 4, 5]");
+        }
+        {
+            let _first = nodes[1].borrow_mut();
+            let _second = nodes[3].borrow_mut();
+            assert_eq!(format!("{list:?}"), "[1, <borrowed>, .., <borrowed>, 5]");
+        }
+    }
+
+    #[test]
+    fn clone() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut cloned = list.clone();
+        assert_eq!(format!("{cloned:?}"), "[1, 2, 3]");
+        assert_eq!(cloned.len(), 3);
+        assert!(!Rc::ptr_eq(
+            list.head.as_ref().unwrap(),
+            cloned.head.as_ref().unwrap()
+        ));
+        // the usual 2 pointers per `Node`, nothing from the original
+        assert_eq!(Rc::strong_count(cloned.head.as_ref().unwrap()), 2);
+
+        for mut elem in cloned.iter_mut() {
+            *elem *= 10;
+        }
+        cloned.push_front(0);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(format!("{list:?}"), "[1, 2]");
+        assert_eq!(format!("{cloned:?}"), "[0, 10, 20, 30]");
+        assert_eq!(cloned.pop_back(), Some(30));
+
+        assert!(List::<i32>::new().clone().is_empty());
+    }
+
+    #[test]
+    fn eq() {
+        let mut list = List::new();
+        let mut other = List::new();
+        assert_eq!(list, other);
+
+        list.push_back(1);
+        list.push_back(2);
+        other.push_front(2);
+        other.push_front(1);
+        assert_eq!(list, other);
+        assert_eq!(list, list.clone());
+
+        other.push_back(3);
+        assert_ne!(list, other);
+        other.pop_back();
+        *other.peek_back_mut().unwrap() = 3;
+        assert_ne!(list, other);
+
+        // comparing did not consume anything
+        assert_eq!(list.len(), 2);
+        assert_eq!(other.pop_front(), Some(1));
+    }
+
+    #[test]
+    fn contains_find_map() {
+        let mut list = List::new();
+        assert!(!list.contains(&String::new()));
+        assert_eq!(list.find_map(|_| Some(())), None);
+
+        list.push_back(String::from("one"));
+        list.push_back(String::from("two"));
+        list.push_back(String::from("three"));
+        assert!(list.contains(&String::from("two")));
+        assert!(!list.contains(&String::from("four")));
+
+        assert_eq!(
+            list.find_map(|elem| elem.strip_prefix('t').map(str::to_owned)),
+            Some(String::from("wo"))
+        );
+        assert_eq!(list.find_map(|elem| elem.find('x')), None);
+
+        // no borrow is left behind, so the `List` can be changed right away
+        *list.peek_front_mut().unwrap() = String::from("zero");
+        assert_eq!(
+            list.find_map(|elem| elem.strip_prefix('z').map(str::len)),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn debug_validate() {
+        let mut list: List<i32> = List::new();
+        list.debug_validate();
+        list.push_back(1);
+        list.debug_validate();
+        list.extend([2, 3]);
+        list.debug_validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "strong pointers")]
+    fn debug_validate_extra_pointer() {
+        let list: List<_> = (0..3).collect();
+        let extra = list.node_at(1);
+        list.debug_validate();
+        drop(extra);
+    }
+
+    #[test]
+    #[should_panic(expected = "broken prev link")]
+    fn debug_validate_broken_prev() {
+        // a broken `List` cannot be dropped safely, so it is leaked instead
+        let list = ManuallyDrop::new((0..3).collect::<List<_>>());
+        let middle = list.node_at(1).unwrap();
+        let last = list.tail.clone().unwrap();
+        // swapping the two `prev`s keeps every count at 2, but the middle `Node` now points back to itself
+        mem::swap(&mut middle.borrow_mut().prev, &mut last.borrow_mut().prev);
+        drop((middle, last));
+        list.debug_validate();
+    }
+
+    #[test]
+    #[should_panic(expected = "len")]
+    fn debug_validate_wrong_len() {
+        let mut list = ManuallyDrop::new((0..3).collect::<List<_>>());
+        list.len = 2;
+        list.debug_validate();
+    }
+
+    #[test]
+    fn cursor() {
+        let mut list = List::new();
+        for i in 1..=3 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current().map(|elem| *elem), Some(1));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_next();
+        // ghost position
+        assert_eq!(cursor.index(), None);
+        assert!(cursor.current().is_none());
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(2));
+        *cursor.current().unwrap() = 30;
+        drop(cursor);
+        assert_eq!(format!("{list:?}"), "[1, 2, 30]");
+
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_prev();
+        assert_eq!(cursor.current().map(|elem| *elem), Some(2));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        drop(cursor);
+
+        let mut empty = List::<i32>::new();
+        let mut cursor = empty.cursor_front_mut();
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), None);
+    }
+
+    #[test]
+    fn cursor_edit() {
+        let mut list = List::new();
+        let mut cursor = list.cursor_front_mut();
+        // at the ghost position, `insert_after` pushes to the front and `insert_before` to the back
+        cursor.insert_after(2);
+        cursor.insert_before(4);
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+        cursor.insert_before(1);
+        assert_eq!(cursor.index(), Some(1));
+        cursor.insert_after(3);
+        assert_eq!(cursor.current().map(|elem| *elem), Some(2));
+        drop(cursor);
+        assert_eq!(format!("{list:?}"), "[1, 2, 3, 4]");
+        list.debug_validate();
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.insert_before(10);
+        cursor.insert_after(20);
+        drop(cursor);
+        assert_eq!(format!("{list:?}"), "[1, 10, 2, 20, 3, 4]");
+        list.debug_validate();
+
+        let mut cursor = list.cursor_front_mut();
+        // head
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_next();
+        cursor.move_next();
+        // middle
+        assert_eq!(cursor.remove_current(), Some(20));
+        assert_eq!(cursor.current().map(|elem| *elem), Some(3));
+        cursor.move_next();
+        // tail, which moves to the ghost position
+        assert_eq!(cursor.remove_current(), Some(4));
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.remove_current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(2));
+        drop(cursor);
+        assert_eq!(format!("{list:?}"), "[10, 2, 3]");
+        assert_eq!(list.len(), 3);
+        list.debug_validate();
+
+        let mut cursor = list.cursor_back_mut();
+        while cursor.remove_current().is_some() {
+            cursor.move_prev();
+        }
+        drop(cursor);
+        assert!(list.is_empty());
+        list.debug_validate();
+        list.push_back(1);
+        list.debug_validate();
+    }
+
+    #[test]
+    fn with_nth() {
+        let mut list = List::new();
+        assert_eq!(list.with_nth(0, |elem: &i32| *elem), None);
+
+        for i in 0..5 {
+            list.push_back(i);
+        }
+        for i in 0..5 {
+            assert_eq!(list.with_nth(i, |elem| *elem), Some(i as i32));
+        }
+        assert_eq!(list.with_nth(5, |elem| *elem), None);
+
+        assert_eq!(
+            list.with_nth_mut(1, |elem| std::mem::replace(elem, 10)),
+            Some(1)
+        );
+        assert_eq!(list.with_nth_mut(4, |elem| *elem *= 10), Some(()));
+        assert_eq!(list.with_nth_mut(7, |elem| *elem = 0), None);
+        assert_eq!(format!("{list:?}"), "[0, 10, 2, 3, 40]");
+        // no extra pointers are left behind
+        list.debug_validate();
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let mut list = List::new();
+        for elem in [3, 1, 4, 1, 5, 9, 2, 6] {
+            list.insert_sorted(elem);
+            list.debug_validate();
+        }
+        assert_eq!(format!("{list:?}"), "[1, 1, 2, 3, 4, 5, 6, 9]");
+        assert_eq!(list.len(), 8);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(9));
+
+        // equal elements keep their insertion order
+        let mut list = List::new();
+        for elem in [(1, 'a'), (0, 'b'), (1, 'c')] {
+            list.insert_sorted(Keyed(elem.0, elem.1));
+        }
+        assert_eq!(
+            list.into_iter().map(|Keyed(_, c)| c).collect::<String>(),
+            "bac"
+        );
+    }
+
+    #[test]
+    fn sort() {
+        let mut list: List<i32> = List::new();
+        list.sort();
+        list.debug_validate();
+        list.push_back(1);
+        list.sort();
+        assert_eq!(format!("{list:?}"), "[1]");
+        list.debug_validate();
+
+        // a simple LCG, so the input is the same every run
+        let mut seed = 42u32;
+        let mut elems: Vec<i32> = (0..1000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (seed >> 16) as i32 % 100
+            })
+            .collect();
+        let mut list: List<_> = elems.iter().copied().collect();
+        list.sort();
+        elems.sort();
+        assert_eq!(list.len(), 1000);
+        list.debug_validate();
+        assert_eq!(list.into_vec(), elems);
+
+        let mut list: List<_> = (0..10).collect();
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(format!("{list:?}"), "[9, 8, 7, 6, 5, 4, 3, 2, 1, 0]");
+        list.debug_validate();
+        list.sort_by(|a, b| (a % 3).cmp(&(b % 3)));
+        assert_eq!(format!("{list:?}"), "[9, 6, 3, 0, 7, 4, 1, 8, 5, 2]");
+        list.debug_validate();
+    }
+
+    #[test]
+    fn sort_stable() {
+        let mut list: List<_> = [(2, 'a'), (1, 'b'), (2, 'c'), (0, 'd'), (1, 'e'), (2, 'f')]
+            .into_iter()
+            .map(|(key, c)| Keyed(key, c))
+            .collect();
+        list.sort();
+        list.debug_validate();
+        assert_eq!(
+            list.into_iter().map(|Keyed(_, c)| c).collect::<String>(),
+            "dbeacf"
+        );
+    }
+
+    #[test]
+    fn sort_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        // long enough that dropping an unlinked chain recursively would overflow the stack
+        let mut list: List<i32> = (0..100_000).rev().collect();
+        let mut comparisons = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                comparisons += 1;
+                assert!(comparisons < 500_000);
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+
+        // every element is still there, in some order, and the `List` is consistent
+        assert_eq!(list.len(), 100_000);
+        list.debug_validate();
+        let mut elems = list.into_vec();
+        elems.sort();
+        assert_eq!(elems, (0..100_000).collect::<Vec<_>>());
+
+        // panicking on the very first comparison
+        let mut list: List<_> = (0..3).collect();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.sort_by(|_, _| panic!())));
+        assert!(result.is_err());
+        assert_eq!(list.len(), 3);
+        list.debug_validate();
+        list.sort();
+        assert_eq!(list.into_vec(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn sort_relinks() {
+        let mut list = List::new();
+        list.push_back(3);
+        let two = list.push_back_handle(2);
+        list.push_back(1);
+        let zero = list.push_front_handle(0);
+
+        list.sort();
+        assert_eq!(format!("{list:?}"), "[0, 1, 2, 3]");
+        list.debug_validate();
+        // the handles follow their `Node`s, which were moved, not overwritten
+        assert_eq!(list.remove(&two), Some(2));
+        assert_eq!(list.remove(&zero), Some(0));
+        assert_eq!(format!("{list:?}"), "[1, 3]");
+        list.debug_validate();
+    }
+
+    /// Compares only by the number
+    #[derive(Debug)]
+    struct Keyed(i32, char);
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Keyed {}
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn retain() {
+        let mut list = List::new();
+        list.retain(|_: &i32| false);
+        list.debug_validate();
+
+        for i in 0..10 {
+            list.push_back(i);
+        }
+        // removes the head, the tail and nodes in the middle
+        list.retain(|&elem| elem % 3 != 0);
+        assert_eq!(format!("{list:?}"), "[1, 2, 4, 5, 7, 8]");
+        list.debug_validate();
+
+        list.retain(|&elem| elem != 8);
+        assert_eq!(format!("{list:?}"), "[1, 2, 4, 5, 7]");
+        list.debug_validate();
+
+        list.retain(|&elem| elem == 4);
+        assert_eq!(format!("{list:?}"), "[4]");
+        list.debug_validate();
+
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        list.debug_validate();
+        list.push_front(1);
+        assert_eq!(list.pop_back(), Some(1));
+    }
+
+    #[test]
+    fn retain_frees_nodes() {
+        let mut list = List::new();
+        let elems: Vec<_> = (0..4).map(Rc::new).collect();
+        for elem in &elems {
+            list.push_back(elem.clone());
+        }
+        list.retain(|elem| **elem % 2 == 0);
+        // the removed elements were dropped along with their `Node`s
+        let counts: Vec<_> = elems.iter().map(Rc::strong_count).collect();
+        assert_eq!(counts, vec![2, 1, 2, 1]);
+        drop(list);
+        assert!(elems.iter().all(|elem| Rc::strong_count(elem) == 1));
+    }
+
+    #[test]
+    fn from_iter_extend() {
+        let mut list: List<_> = (1..=3).collect();
+        assert_eq!(format!("{list:?}"), "[1, 2, 3]");
+        assert_eq!(list.len(), 3);
+
+        list.extend(vec![4, 5]);
+        list.extend(std::iter::empty());
+        assert_eq!(format!("{list:?}"), "[1, 2, 3, 4, 5]");
+        assert_eq!(list.len(), 5);
+        list.debug_validate();
+
+        let list: List<i32> = std::iter::empty().collect();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn vec_conversions() {
+        let list = List::from(vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        list.debug_validate();
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let mut deque = VecDeque::from(vec![2, 3]);
+        deque.push_front(1);
+        let list = List::from(deque);
+        list.debug_validate();
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let list = List::from(Vec::<i32>::new());
+        assert!(list.is_empty());
+        assert_eq!(list.into_vec(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn for_each() {
+        let mut list: List<i32> = List::new();
+        list.for_each_ref(|_| panic!("empty"));
+        list.for_each_mut(|_| panic!("empty"));
+
+        list.extend([1, 2, 3]);
+        let mut seen = Vec::new();
+        list.for_each_ref(|elem| seen.push(*elem));
+        assert_eq!(seen, vec![1, 2, 3]);
+
+        list.for_each_mut(|elem| *elem *= 2);
+        assert_eq!(format!("{list:?}"), "[2, 4, 6]");
+        list.debug_validate();
+    }
+
+    #[test]
+    fn remove_first_where() {
+        let mut list: List<_> = ["a", "b", "c", "b"].into_iter().collect();
+        assert_eq!(list.remove_first_where(|&elem| elem == "b"), Some("b"));
+        assert_eq!(format!("{list:?}"), r#"["a", "c", "b"]"#);
+        assert_eq!(list.remove_first_where(|&elem| elem == "x"), None);
+        assert_eq!(list.remove_first_where(|_| true), Some("a"));
+        assert_eq!(list.remove_first_where(|&elem| elem == "b"), Some("b"));
+        assert_eq!(format!("{list:?}"), r#"["c"]"#);
+        assert_eq!(list.len(), 1);
+        list.debug_validate();
+        assert_eq!(list.remove_first_where(|_| true), Some("c"));
+        assert_eq!(list.remove_first_where(|_| true), None);
+        list.debug_validate();
+    }
+
+    #[test]
+    fn reverse() {
+        let mut list: List<i32> = List::new();
+        list.reverse();
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        list.reverse();
+        assert_eq!(format!("{list:?}"), "[1]");
+        list.debug_validate();
+
+        list.extend([2, 3, 4]);
+        list.reverse();
+        assert_eq!(format!("{list:?}"), "[4, 3, 2, 1]");
+        assert_eq!(list.len(), 4);
+        list.debug_validate();
+
+        list.push_front(5);
+        list.push_back(0);
+        assert_eq!(list.pop_back(), Some(0));
+        list.reverse();
+        assert_eq!(format!("{list:?}"), "[1, 2, 3, 4, 5]");
+        list.debug_validate();
+    }
+
+    #[test]
+    fn rotate() {
+        let mut list: List<i32> = List::new();
+        list.move_front_to_back();
+        list.move_back_to_front();
+        list.push_back(1);
+        list.move_front_to_back();
+        list.move_back_to_front();
+        assert_eq!(format!("{list:?}"), "[1]");
+        list.debug_validate();
+
+        list.extend([2, 3, 4]);
+        list.move_front_to_back();
+        assert_eq!(format!("{list:?}"), "[2, 3, 4, 1]");
+        list.debug_validate();
+        list.move_front_to_back();
+        assert_eq!(format!("{list:?}"), "[3, 4, 1, 2]");
+        list.move_back_to_front();
+        list.move_back_to_front();
+        list.move_back_to_front();
+        assert_eq!(format!("{list:?}"), "[4, 1, 2, 3]");
+        list.debug_validate();
+
+        let mut pair: List<_> = [1, 2].into_iter().collect();
+        pair.move_back_to_front();
+        assert_eq!(format!("{pair:?}"), "[2, 1]");
+        pair.debug_validate();
+        assert_eq!(pair.pop_back(), Some(1));
+        assert_eq!(pair.pop_back(), Some(2));
+    }
+
+    #[test]
+    fn handles() {
+        let mut list = List::new();
+        let b = list.push_back_handle('b');
+        let a = list.push_front_handle('a');
+        let c = list.push_back_handle('c');
+        let d = list.push_back_handle('d');
+        list.debug_validate();
+
+        // middle, then both ends
+        assert_eq!(list.remove(&c), Some('c'));
+        assert_eq!(format!("{list:?}"), "['a', 'b', 'd']");
+        list.debug_validate();
+        assert_eq!(list.remove(&a), Some('a'));
+        assert_eq!(list.remove(&d), Some('d'));
+        assert_eq!(format!("{list:?}"), "['b']");
+        list.debug_validate();
+
+        // removed elements cannot be removed again
+        assert_eq!(list.remove(&c), None);
+        assert_eq!(list.remove(&a.clone()), None);
+
+        // popped elements are gone too
+        let e = list.push_back_handle('e');
+        assert_eq!(list.pop_back(), Some('e'));
+        assert_eq!(list.remove(&e), None);
+
+        // the ends of another `List` are rejected
+        let mut other = List::new();
+        let x = other.push_back_handle('x');
+        assert_eq!(list.remove(&x), None);
+        assert_eq!(other.remove(&x), Some('x'));
+
+        assert_eq!(list.remove(&b), Some('b'));
+        assert!(list.is_empty());
+        list.debug_validate();
+    }
+
+    #[test]
+    fn foreign_handles() {
+        let mut list = List::new();
+        let mut other = List::new();
+        other.push_back('x');
+        let y = other.push_back_handle('y');
+        other.push_back('z');
+
+        // a `Node` in the middle of another `List` is not touched, not even when this one is empty
+        assert_eq!(list.remove(&y), None);
+        assert!(list.is_empty());
+        list.push_back('a');
+        assert_eq!(list.remove(&y), None);
+        assert_eq!(list.len(), 1);
+        assert_eq!(other.len(), 3);
+        list.debug_validate();
+        other.debug_validate();
+
+        assert_eq!(other.remove(&y), Some('y'));
+        assert_eq!(format!("{other:?}"), "['x', 'z']");
+        other.debug_validate();
+    }
+
+    #[test]
+    fn foreign_handle_borrowed() {
+        let mut list = List::new();
+        list.push_back('a');
+        let mut other = List::new();
+        let x = other.push_front_handle('x');
+
+        // the other `List` is editing the `Node` right now, which still only means it is not ours
+        let front = other.peek_front_mut().unwrap();
+        assert_eq!(list.remove(&x), None);
+        drop(front);
+        assert_eq!(list.len(), 1);
+        assert_eq!(other.remove(&x), Some('x'));
+    }
+
+    #[test]
+    fn move_to_front() {
+        let mut list = List::new();
+        let a = list.push_back_handle('a');
+        let b = list.push_back_handle('b');
+        let c = list.push_back_handle('c');
+
+        // the back, the middle, and the front, which is already in place
+        assert!(list.move_to_front(&c));
+        assert_eq!(format!("{list:?}"), "['c', 'a', 'b']");
+        list.debug_validate();
+        assert!(list.move_to_front(&a));
+        assert_eq!(format!("{list:?}"), "['a', 'c', 'b']");
+        assert!(list.move_to_front(&a));
+        assert_eq!(format!("{list:?}"), "['a', 'c', 'b']");
+        assert_eq!(list.len(), 3);
+        list.debug_validate();
+
+        // the handles keep pointing to the same `Node`s
+        assert_eq!(list.remove(&c), Some('c'));
+        assert_eq!(format!("{list:?}"), "['a', 'b']");
+
+        // removed and foreign `Node`s are not moved
+        assert!(!list.move_to_front(&c));
+        let mut other = List::new();
+        let x = other.push_back_handle('x');
+        assert!(!list.move_to_front(&x));
+        assert_eq!(format!("{list:?}"), "['a', 'b']");
+        assert!(list.move_to_front(&b));
+        assert_eq!(format!("{list:?}"), "['b', 'a']");
+        list.debug_validate();
+    }
+
+    #[test]
+    fn handles_follow_split_off() {
+        // both the shorter prefix and the shorter suffix case
+        for at in [1, 4] {
+            let mut list = List::new();
+            let handles: Vec<_> = (0..5).map(|i| list.push_back_handle(i)).collect();
+            let mut suffix = list.split_off(at);
+            list.debug_validate();
+            suffix.debug_validate();
+
+            for (i, handle) in handles.iter().enumerate() {
+                assert!(handle.is_alive());
+                let (owner, not_owner) = if i < at {
+                    (&mut list, &mut suffix)
+                } else {
+                    (&mut suffix, &mut list)
+                };
+                assert_eq!(not_owner.remove(handle), None);
+                assert_eq!(owner.remove(handle), Some(i));
+            }
+            assert!(list.is_empty());
+            assert!(suffix.is_empty());
+        }
+    }
+
+    #[test]
+    fn downgrade() {
+        let mut list = List::new();
+        assert!(list.downgrade_front().is_none());
+        assert!(list.downgrade_back().is_none());
+
+        list.push_back(1);
+        let only = list.downgrade_front().unwrap();
+        assert!(only.is_alive());
+        assert_eq!(only.strong_count(), 2);
+        assert_eq!(list.downgrade_back().unwrap().upgrade_value(), Some(1));
+
+        list.extend([2, 3]);
+        let front = list.downgrade_front().unwrap();
+        let back = list.downgrade_back().unwrap();
+        // observing does not change any counts
+        list.debug_validate();
+        assert_eq!(back.strong_count(), 2);
+
+        *list.peek_front_mut().unwrap() = 10;
+        assert_eq!(front.upgrade_value(), Some(10));
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert!(!back.is_alive());
+        assert_eq!(back.strong_count(), 0);
+        assert_eq!(back.upgrade_value(), None);
+
+        // the handle follows the `Node`, not the position
+        assert_eq!(list.pop_back(), Some(2));
+        assert!(front.is_alive());
+        assert_eq!(list.remove(&front), Some(10));
+        assert!(!front.is_alive());
+
+        list.push_back(4);
+        let last = list.downgrade_back().unwrap();
+        drop(list);
+        assert!(!last.is_alive());
+    }
+
+    #[test]
+    fn split_off() {
+        fn collect(list: &List<i32>) -> Vec<i32> {
+            list.iter().map(|elem| *elem).collect()
+        }
+
+        for at in 0..=5 {
+            let mut list = List::new();
+            for i in 0..5 {
+                list.push_back(i);
+            }
+            let mut suffix = list.split_off(at);
+            assert_eq!(collect(&list), (0..at as i32).collect::<Vec<_>>());
+            assert_eq!(collect(&suffix), (at as i32..5).collect::<Vec<_>>());
+            assert_eq!(list.len(), at);
+            assert_eq!(suffix.len(), 5 - at);
+
+            // both sides are fully working deques, with correct ends in both directions
+            list.push_back(10);
+            suffix.push_front(20);
+            assert_eq!(list.pop_back(), Some(10));
+            assert_eq!(suffix.pop_front(), Some(20));
+            assert_eq!(
+                list.pop_back(),
+                if at > 0 { Some(at as i32 - 1) } else { None }
+            );
+            assert_eq!(suffix.pop_back(), if at < 5 { Some(4) } else { None });
+            assert_eq!(list.into_iter().rev().count(), at.saturating_sub(1));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_off_out_of_bounds() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.split_off(2);
+    }
+
     #[test]
     fn peek() {
         let mut list = List::new();
@@ -267,6 +2026,130 @@ mod test {
         assert_eq!(&mut *list.peek_back_mut().unwrap(), &mut 1);
     }
 
+    #[test]
+    fn update() {
+        let mut list: List<i32> = List::new();
+        assert_eq!(list.update_front(|elem| *elem += 1), None);
+        assert_eq!(list.update_back(|elem| *elem += 1), None);
+
+        list.extend([1, 2, 3]);
+        assert_eq!(list.update_front(|elem| *elem *= 10), Some(()));
+        assert_eq!(
+            list.update_back(|elem| {
+                *elem += 1;
+                *elem
+            }),
+            Some(4)
+        );
+        // the guards are gone, so the ends can be popped right away
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_back(), Some(4));
+
+        // with a single element, both ends update the same `Node`
+        list.update_front(|elem| *elem = 7);
+        assert_eq!(list.update_back(|elem| *elem), Some(7));
+        list.debug_validate();
+    }
+
+    #[test]
+    fn peek_cloned() {
+        let mut list = List::new();
+        assert_eq!(list.peek_front_cloned(), None);
+        assert_eq!(list.peek_back_cloned(), None);
+
+        list.push_back(String::from("a"));
+        assert_eq!(list.peek_front_cloned().as_deref(), Some("a"));
+        assert_eq!(list.peek_back_cloned().as_deref(), Some("a"));
+
+        list.push_back(String::from("b"));
+        let front = list.peek_front_cloned().unwrap();
+        // no borrow is held, so the `List` can be changed while `front` is still around
+        list.pop_front();
+        list.push_front(String::from("c"));
+        assert_eq!(front, "a");
+        assert_eq!(list.peek_front_cloned().as_deref(), Some("c"));
+        assert_eq!(list.peek_back_cloned().as_deref(), Some("b"));
+        list.debug_validate();
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = List::new();
+        assert!(list.iter().next().is_none());
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut iter = list.iter();
+        assert_eq!(*iter.next().unwrap(), 1);
+        assert_eq!(*iter.next().unwrap(), 2);
+        assert_eq!(*iter.next().unwrap(), 3);
+        assert!(iter.next().is_none());
+
+        // guards can outlive the step that produced them, and shared borrows can overlap
+        let guards: Vec<_> = list.iter().collect();
+        let first = list.peek_front().unwrap();
+        assert_eq!(*first, *guards[0]);
+        assert_eq!(guards.iter().map(|elem| **elem).sum::<i32>(), 6);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        for mut elem in list.iter_mut() {
+            *elem *= 10;
+        }
+        // several `RefMut`s at once, one per `Node`
+        let mut guards: Vec<_> = list.iter_mut().collect();
+        *guards[0] += 1;
+        *guards[2] += 3;
+        drop(guards);
+
+        assert_eq!(
+            list.iter().map(|elem| *elem).collect::<Vec<_>>(),
+            vec![11, 20, 33]
+        );
+        assert_eq!(list.pop_back(), Some(33));
+    }
+
+    #[test]
+    fn try_peek() {
+        let mut list = List::new();
+        assert!(list.try_peek_front().unwrap().is_none());
+        assert!(list.try_peek_back_mut().unwrap().is_none());
+
+        list.push_back(1);
+        list.push_back(2);
+        assert_eq!(*list.try_peek_front().unwrap().unwrap(), 1);
+        assert_eq!(*list.try_peek_back().unwrap().unwrap(), 2);
+        *list.try_peek_front_mut().unwrap().unwrap() = 10;
+        *list.try_peek_back_mut().unwrap().unwrap() = 20;
+
+        // simulate someone else holding on to the `Node`s
+        let head = list.head.clone().unwrap();
+        let tail = list.tail.clone().unwrap();
+        {
+            let _guard = head.borrow_mut();
+            assert!(list.try_peek_front().is_err());
+            assert!(list.try_peek_front_mut().is_err());
+            assert_eq!(*list.try_peek_back().unwrap().unwrap(), 20);
+        }
+        {
+            let _guard = tail.borrow();
+            assert!(list.try_peek_back().is_ok());
+            assert!(list.try_peek_back_mut().is_err());
+            assert_eq!(*list.try_peek_front_mut().unwrap().unwrap(), 10);
+        }
+        drop((head, tail));
+        assert_eq!(list.pop_back(), Some(20));
+        assert_eq!(list.pop_back(), Some(10));
+    }
+
     #[test]
     fn into_iter() {
         let mut list = List::new();
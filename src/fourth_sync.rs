@@ -0,0 +1,366 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// The same deque as in `fourth`, translated to the multi-threaded world: `Rc` becomes `Arc` and `RefCell` becomes
+/// `Mutex`. `RefCell` is not `Sync`, as its borrow flag is a plain counter, while a `Mutex` makes the threads
+/// wait for each other, so `List<T>` is `Send` and `Sync` as long as `T` is `Send`.
+/// The invariant is unchanged: every `Node` has exactly 2 strong pointers to it.
+///
+/// Changing the `List` still needs `&mut self`, so threads that push to the same `List` have to put it behind
+/// an `Arc<Mutex<List<T>>>`; the `Mutex`es in the `Node`s are what make the `List` allowed to be there at all.
+///
+/// Deadlock hazards, which `RefCell` turned into `panic`s, but which simply hang with a `Mutex`:
+/// - a `Mutex` cannot be locked twice, not even for reading. In a single element `List`, `peek_front` and `peek_back`
+///   lock the same `Node`, so holding the guard of one while asking for the other never returns.
+/// - locking two `Node`s in different orders from two threads (front to back in one, back to front in the other)
+///   can leave both waiting on each other. The methods here never hold more than one lock at a time, except
+///   while linking a brand new `Node` that no other thread can see yet.
+/// - the same goes for the outer `Mutex<List<T>>`: a thread holding it must not wait on a guard of another thread.
+pub struct List<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+}
+
+type Link<T> = Option<Arc<Mutex<Node<T>>>>;
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+    prev: Link<T>,
+}
+
+impl<T> Node<T> {
+    fn new(elem: T) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(Node {
+            elem,
+            prev: None,
+            next: None,
+        }))
+    }
+}
+
+/// A `Mutex` is poisoned if a thread `panic`s while holding its guard. The only thing a caller can touch through
+/// a guard is `elem`, never the links, so the `List` is still consistent and the poison can be ignored.
+fn lock<T>(node: &Mutex<Node<T>>) -> MutexGuard<'_, Node<T>> {
+    node.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List {
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        let new_head = Node::new(elem);
+        match self.head.take() {
+            Some(old_head) => {
+                // each lock is released at the end of its statement, so only one is held at a time
+                lock(&old_head).prev = Some(new_head.clone());
+                lock(&new_head).next = Some(old_head);
+            }
+            None => {
+                self.tail = Some(new_head.clone());
+            }
+        }
+        self.head = Some(new_head);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        let new_tail = Node::new(elem);
+        match self.tail.take() {
+            Some(old_tail) => {
+                lock(&old_tail).next = Some(new_tail.clone());
+                lock(&new_tail).prev = Some(old_tail);
+            }
+            None => {
+                self.head = Some(new_tail.clone());
+            }
+        }
+        self.tail = Some(new_tail);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|old_head| {
+            // the guard of `old_head` is dropped before `new_head` is locked
+            let next = lock(&old_head).next.take();
+            match next {
+                Some(new_head) => {
+                    lock(&new_head).prev = None;
+                    self.head = Some(new_head);
+                }
+                None => {
+                    self.tail = None;
+                }
+            }
+            self.len -= 1;
+            Self::into_elem(old_head)
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.take().map(|old_tail| {
+            let prev = lock(&old_tail).prev.take();
+            match prev {
+                Some(new_tail) => {
+                    lock(&new_tail).next = None;
+                    self.tail = Some(new_tail);
+                }
+                None => {
+                    self.head = None;
+                }
+            }
+            self.len -= 1;
+            Self::into_elem(old_tail)
+        })
+    }
+
+    /// Locks the first `Node`. The returned guard has to be dropped before locking the same `Node` again,
+    /// see the deadlock hazards above.
+    pub fn peek_front(&self) -> Option<Guard<'_, T>> {
+        self.head.as_ref().map(|node| Guard(lock(node)))
+    }
+
+    pub fn peek_back(&self) -> Option<Guard<'_, T>> {
+        self.tail.as_ref().map(|node| Guard(lock(node)))
+    }
+
+    pub fn peek_front_mut(&mut self) -> Option<Guard<'_, T>> {
+        self.head.as_ref().map(|node| Guard(lock(node)))
+    }
+
+    pub fn peek_back_mut(&mut self) -> Option<Guard<'_, T>> {
+        self.tail.as_ref().map(|node| Guard(lock(node)))
+    }
+
+    /// Both neighbours have let go of the `Node` by now, so the local pointer is the only one left
+    fn into_elem(node: Arc<Mutex<Node<T>>>) -> T {
+        let node = Arc::try_unwrap(node).ok().unwrap();
+        node.into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+            .elem
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Same as in `fourth`: the `Arc` cycles have to be broken by hand, one `Node` at a time
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+pub struct IntoIter<T>(List<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+/// `MutexGuard` has no stable `map` like `Ref::map`, so this wraps the guard of a whole `Node`
+/// and only lets the element through
+pub struct Guard<'a, T>(MutexGuard<'a, Node<T>>);
+
+impl<T> Deref for Guard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0.elem
+    }
+}
+
+impl<T> DerefMut for Guard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0.elem
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::List;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    #[test]
+    fn basics() {
+        let mut list = List::new();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_back(3);
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), Some(3));
+
+        list.push_back(4);
+        list.push_front(5);
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn peek() {
+        let mut list = List::new();
+        assert!(list.peek_front().is_none());
+        assert!(list.peek_back().is_none());
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        assert_eq!(*list.peek_front().unwrap(), 3);
+        assert_eq!(*list.peek_back().unwrap(), 1);
+        *list.peek_back_mut().unwrap() = 10;
+        *list.peek_front_mut().unwrap() = 30;
+        assert_eq!(list.pop_back(), Some(10));
+        assert_eq!(list.pop_front(), Some(30));
+    }
+
+    #[test]
+    fn into_iter() {
+        let list: List<_> = (1..=3).collect();
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn send_sync() {
+        fn is_send<T: Send>() {}
+        fn is_sync<T: Sync>() {}
+
+        is_send::<List<i32>>();
+        is_sync::<List<i32>>();
+        // a `Mutex` only needs `T: Send`, as just one thread can reach the element at a time
+        is_sync::<List<std::cell::Cell<i32>>>();
+    }
+
+    #[test]
+    fn move_to_thread() {
+        let list: List<_> = (0..100).collect();
+        let sum = thread::spawn(move || list.into_iter().sum::<i32>())
+            .join()
+            .unwrap();
+        assert_eq!(sum, (0..100).sum::<i32>());
+    }
+
+    #[test]
+    fn push_from_threads() {
+        let list = Arc::new(Mutex::new(List::new()));
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let list = list.clone();
+                thread::spawn(move || {
+                    for j in 0..100 {
+                        // the outer lock is only held for a single push
+                        let mut list = list.lock().unwrap();
+                        if j % 2 == 0 {
+                            list.push_front(i * 100 + j);
+                        } else {
+                            list.push_back(i * 100 + j);
+                        }
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let list = Arc::try_unwrap(list).ok().unwrap().into_inner().unwrap();
+        assert_eq!(list.len(), 400);
+        let mut elems: Vec<_> = list.into_iter().collect();
+        elems.sort();
+        assert_eq!(elems, (0..400).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn peek_from_threads() {
+        let list: List<_> = (1..=3).collect();
+
+        // `Sync`: shared references can be used from several threads, the `Node`s are locked one at a time
+        thread::scope(|s| {
+            s.spawn(|| assert_eq!(*list.peek_front().unwrap(), 1));
+            s.spawn(|| assert_eq!(*list.peek_back().unwrap(), 3));
+        });
+    }
+
+    #[test]
+    fn no_leak() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        let first = Arc::downgrade(list.head.as_ref().unwrap());
+        let last = Arc::downgrade(list.tail.as_ref().unwrap());
+
+        drop(list);
+        assert!(first.upgrade().is_none());
+        assert!(last.upgrade().is_none());
+    }
+
+    #[test]
+    fn long_list_drop() {
+        let list: List<_> = (0..100_000).collect();
+        drop(list);
+    }
+}
@@ -0,0 +1,300 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::rc::{Rc, Weak};
+
+/// The same deque as in `fourth`, but the backward pointers (`prev` and the `List`'s `tail`) are `Weak`.
+/// This changes the invariant from "every `Node` has exactly 2 strong pointers to it" to
+/// "every `Node` has exactly 1 strong pointer to it: from the `Node` before it, or the `List` for the first one".
+/// `Weak` pointers do not keep a `Node` alive, so there are no `Rc` cycles anymore, and forgetting to unlink
+/// a `Node` cannot leak the whole `List`.
+///
+/// Tradeoffs compared to `fourth`:
+/// - `Weak` has to be `upgrade`d to be used, which creates a temporary `Rc` and checks that the `Node` is still
+///   alive, even though it always is, so walking backwards costs a bit more.
+/// - the `tail` is not an `Rc`, so `peek_back` cannot simply borrow from it; it needs a small `unsafe` block
+///   that relies on the `Node` being kept alive by the one before it.
+/// - `Drop` is still implemented, but only to avoid recursion on long `List`s, not to break cycles.
+pub struct List<T> {
+    head: Link<T>,
+    tail: WeakLink<T>,
+    len: usize,
+}
+
+type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+
+type WeakLink<T> = Option<Weak<RefCell<Node<T>>>>;
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+    prev: WeakLink<T>,
+}
+
+impl<T> Node<T> {
+    fn new(elem: T) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Node {
+            elem,
+            next: None,
+            prev: None,
+        }))
+    }
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List {
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        let new_head = Node::new(elem);
+        match self.head.take() {
+            Some(old_head) => {
+                old_head.borrow_mut().prev = Some(Rc::downgrade(&new_head));
+                // the only strong pointer to `old_head` moves from the `List` to `new_head`
+                new_head.borrow_mut().next = Some(old_head);
+            }
+            None => {
+                self.tail = Some(Rc::downgrade(&new_head));
+            }
+        }
+        self.head = Some(new_head);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        let new_tail = Node::new(elem);
+        let weak = Rc::downgrade(&new_tail);
+        match self
+            .tail
+            .replace(weak)
+            .and_then(|old_tail| old_tail.upgrade())
+        {
+            Some(old_tail) => {
+                new_tail.borrow_mut().prev = Some(Rc::downgrade(&old_tail));
+                old_tail.borrow_mut().next = Some(new_tail);
+            }
+            None => {
+                self.head = Some(new_tail);
+            }
+        }
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|old_head| {
+            match old_head.borrow_mut().next.take() {
+                Some(new_head) => {
+                    new_head.borrow_mut().prev = None;
+                    self.head = Some(new_head);
+                }
+                None => {
+                    self.tail = None;
+                }
+            }
+            self.len -= 1;
+            // `old_head` is the only strong pointer left, the `Weak` one in the next `Node` was cleared
+            Rc::try_unwrap(old_head).ok().unwrap().into_inner().elem
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        // the `Node` is kept alive by the one before it (or the `List`), so `upgrade` always succeeds
+        let old_tail = self.tail.take()?.upgrade().unwrap();
+        let prev = old_tail.borrow_mut().prev.take();
+        match prev.and_then(|prev| prev.upgrade()) {
+            Some(new_tail) => {
+                // drop the strong pointer from the `Node` before, now the local one is the only one left
+                new_tail.borrow_mut().next = None;
+                self.tail = Some(Rc::downgrade(&new_tail));
+            }
+            None => {
+                self.head = None;
+            }
+        }
+        self.len -= 1;
+        Some(Rc::try_unwrap(old_tail).ok().unwrap().into_inner().elem)
+    }
+
+    pub fn peek_front(&self) -> Option<Ref<'_, T>> {
+        self.head
+            .as_ref()
+            .map(|node| Ref::map(node.borrow(), |node| &node.elem))
+    }
+
+    pub fn peek_front_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.head
+            .as_ref()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+
+    pub fn peek_back(&self) -> Option<Ref<'_, T>> {
+        self.tail_cell()
+            .map(|node| Ref::map(node.borrow(), |node| &node.elem))
+    }
+
+    pub fn peek_back_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.tail_cell()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+
+    /// An `upgrade`d `Rc` would be a local, so a `Ref` could not be returned from it. Instead, the `Weak` is turned
+    /// into a plain reference that lives as long as the borrow of the `List`.
+    fn tail_cell(&self) -> Option<&RefCell<Node<T>>> {
+        // SAFETY: the last `Node` is kept alive by a strong pointer in the `Node` before it (or in `head`),
+        // and that can only change through `&mut self` methods, which cannot be called while `self` is borrowed
+        self.tail.as_ref().map(|tail| unsafe { &*tail.as_ptr() })
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// There are no cycles, so simply dropping `head` would free everything, but it would do it recursively,
+/// one stack frame per `Node`, so the `Node`s are still unlinked in a loop
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut cur = self.head.take();
+        while let Some(node) = cur {
+            cur = node.borrow_mut().next.take();
+        }
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+pub struct IntoIter<T>(List<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::List;
+    use std::rc::Rc;
+
+    #[test]
+    fn basics() {
+        let mut list = List::new();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_back(3);
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), Some(3));
+
+        list.push_back(4);
+        list.push_front(5);
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn peek() {
+        let mut list = List::new();
+        assert!(list.peek_front().is_none());
+        assert!(list.peek_back().is_none());
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        assert_eq!(*list.peek_front().unwrap(), 3);
+        assert_eq!(*list.peek_back().unwrap(), 1);
+        *list.peek_back_mut().unwrap() = 10;
+        *list.peek_front_mut().unwrap() = 30;
+        assert_eq!(list.pop_back(), Some(10));
+        assert_eq!(list.pop_front(), Some(30));
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut list = List::new();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next_back(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn single_strong_pointer() {
+        let mut list = List::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        // every `Node` has exactly one strong pointer, and one `Weak` from its neighbour or the `tail`
+        let mut cur = list.head.clone();
+        while let Some(node) = cur {
+            // -1 for the local `node`
+            assert_eq!(Rc::strong_count(&node) - 1, 1);
+            assert_eq!(Rc::weak_count(&node), 1);
+            cur = node.borrow().next.clone();
+        }
+    }
+
+    #[test]
+    fn no_leak() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        let first = Rc::downgrade(list.head.as_ref().unwrap());
+        let last = list.tail.clone().unwrap();
+
+        drop(list);
+        assert!(first.upgrade().is_none());
+        assert!(last.upgrade().is_none());
+    }
+
+    #[test]
+    fn long_list_drop() {
+        let mut list = List::new();
+        for i in 0..100_000 {
+            list.push_back(i);
+        }
+        drop(list);
+    }
+}
@@ -0,0 +1,314 @@
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// A singly-linked `List` that is generic over the smart pointer between its `Node`s.
+/// With `Boxed` it is the uniquely owned stack from `second`, with `Counted` it is the persistent `List` from `third`,
+/// and with `AtomicCounted` it is the same persistent `List`, but `Send` and `Sync`.
+/// The pointer is picked with a marker type implementing `PointerKind`, as `List<T, Box>` cannot be written:
+/// `Box` by itself is not a type, only `Box<Something>` is, so the marker holds a generic associated type instead.
+pub struct List<T, P: PointerKind> {
+    head: Link<T, P>,
+}
+
+type Link<T, P> = Option<<P as PointerKind>::Pointer<Node<T, P>>>;
+
+struct Node<T, P: PointerKind> {
+    elem: T,
+    next: Link<T, P>,
+}
+
+/// Abstracts over a family of smart pointers, `Pointer<U>` being the actual pointer type for a given `U`
+pub trait PointerKind: Sized {
+    type Pointer<U>: Deref<Target = U>;
+
+    fn new<U>(value: U) -> Self::Pointer<U>;
+
+    /// Gets the value out if this is the only pointer to it, otherwise gives the pointer back
+    fn try_unwrap<U>(pointer: Self::Pointer<U>) -> Result<U, Self::Pointer<U>>;
+
+    /// Drops the pointer, and gets the value out if it was the last one. Unlike `try_unwrap`, when several
+    /// threads drop their pointers at once, exactly one of them gets the value, which is what `Drop` needs
+    fn into_inner_if_last<U>(pointer: Self::Pointer<U>) -> Option<U>;
+}
+
+/// Pointers that are always the only owner of their value, so it can always be moved out
+pub trait UniqueKind: PointerKind {
+    fn into_inner<U>(pointer: Self::Pointer<U>) -> U;
+}
+
+/// Pointers that can be cloned cheaply to share their value, so sublists can be shared as well
+pub trait SharedKind: PointerKind {
+    fn share<U>(pointer: &Self::Pointer<U>) -> Self::Pointer<U>;
+}
+
+/// `Box`: unique ownership
+pub struct Boxed;
+
+/// `Rc`: shared ownership within one thread
+pub struct Counted;
+
+/// `Arc`: shared ownership across threads
+pub struct AtomicCounted;
+
+impl PointerKind for Boxed {
+    type Pointer<U> = Box<U>;
+
+    fn new<U>(value: U) -> Box<U> {
+        Box::new(value)
+    }
+
+    fn try_unwrap<U>(pointer: Box<U>) -> Result<U, Box<U>> {
+        Ok(*pointer)
+    }
+
+    fn into_inner_if_last<U>(pointer: Box<U>) -> Option<U> {
+        Some(*pointer)
+    }
+}
+
+impl UniqueKind for Boxed {
+    fn into_inner<U>(pointer: Box<U>) -> U {
+        *pointer
+    }
+}
+
+impl PointerKind for Counted {
+    type Pointer<U> = Rc<U>;
+
+    fn new<U>(value: U) -> Rc<U> {
+        Rc::new(value)
+    }
+
+    fn try_unwrap<U>(pointer: Rc<U>) -> Result<U, Rc<U>> {
+        Rc::try_unwrap(pointer)
+    }
+
+    fn into_inner_if_last<U>(pointer: Rc<U>) -> Option<U> {
+        Rc::into_inner(pointer)
+    }
+}
+
+impl SharedKind for Counted {
+    fn share<U>(pointer: &Rc<U>) -> Rc<U> {
+        Rc::clone(pointer)
+    }
+}
+
+impl PointerKind for AtomicCounted {
+    type Pointer<U> = Arc<U>;
+
+    fn new<U>(value: U) -> Arc<U> {
+        Arc::new(value)
+    }
+
+    fn try_unwrap<U>(pointer: Arc<U>) -> Result<U, Arc<U>> {
+        Arc::try_unwrap(pointer)
+    }
+
+    fn into_inner_if_last<U>(pointer: Arc<U>) -> Option<U> {
+        Arc::into_inner(pointer)
+    }
+}
+
+impl SharedKind for AtomicCounted {
+    fn share<U>(pointer: &Arc<U>) -> Arc<U> {
+        Arc::clone(pointer)
+    }
+}
+
+/// Operations that work the same, no matter the kind of pointer
+impl<T, P: PointerKind> List<T, P> {
+    pub fn new() -> Self {
+        List { head: None }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T, P> {
+        Iter {
+            // every `Pointer` is `Deref`, so `as_deref` works just like with `Box` or `Rc`
+            next: self.head.as_deref(),
+        }
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    pub fn push(&mut self, elem: T) {
+        let new_node = P::new(Node {
+            elem,
+            next: self.head.take(),
+        });
+        self.head = Some(new_node);
+    }
+}
+
+/// Moving elements out is only possible if the `Node` is never shared
+impl<T, P: UniqueKind> List<T, P> {
+    pub fn pop(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            let node = P::into_inner(node);
+            self.head = node.next;
+            node.elem
+        })
+    }
+}
+
+/// The persistent operations of `third`, which need to share the rest of the `List`
+impl<T, P: SharedKind> List<T, P> {
+    /// Return a new `List` that has the provided element added to the front, the original `List` is still usable
+    pub fn prepend(&self, elem: T) -> List<T, P> {
+        List {
+            head: Some(P::new(Node {
+                elem,
+                next: self.head.as_ref().map(P::share),
+            })),
+        }
+    }
+
+    /// Return a `List` that contains everything but the first element of this one
+    pub fn tail(&self) -> List<T, P> {
+        List {
+            head: self
+                .head
+                .as_ref()
+                .and_then(|node| node.next.as_ref().map(P::share)),
+        }
+    }
+}
+
+impl<T, P: PointerKind> Default for List<T, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One iterative `Drop` for every kind: a `Box` always unwraps, a shared pointer stops at the first `Node` used elsewhere.
+/// With `Arc`, whichever thread drops the last pointer to a `Node` gets it back and carries on freeing the chain.
+impl<T, P: PointerKind> Drop for List<T, P> {
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            if let Some(mut node) = P::into_inner_if_last(node) {
+                head = node.next.take();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+pub struct Iter<'a, T, P: PointerKind> {
+    next: Option<&'a Node<T, P>>,
+}
+
+impl<'a, T, P: PointerKind> Iterator for Iter<'a, T, P> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AtomicCounted, Boxed, Counted, List, PointerKind};
+    use std::sync::Barrier;
+    use std::thread;
+
+    fn push_peek_iter<P: PointerKind>() {
+        let mut list = List::<_, P>::new();
+        assert_eq!(list.peek(), None);
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.peek(), Some(&3));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn every_kind() {
+        push_peek_iter::<Boxed>();
+        push_peek_iter::<Counted>();
+        push_peek_iter::<AtomicCounted>();
+    }
+
+    #[test]
+    fn boxed_pop() {
+        let mut list = List::<_, Boxed>::new();
+        assert_eq!(list.pop(), None);
+
+        list.push(1);
+        list.push(2);
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn counted_sharing() {
+        let list = List::<_, Counted>::new().prepend(1).prepend(2);
+        let other = list.tail().prepend(3);
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(other.iter().copied().collect::<Vec<_>>(), vec![3, 1]);
+        assert_eq!(list.tail().tail().peek(), None);
+    }
+
+    #[test]
+    fn atomic_counted_across_threads() {
+        fn is_send<T: Send>() {}
+        fn is_sync<T: Sync>() {}
+        is_send::<List<i32, AtomicCounted>>();
+        is_sync::<List<i32, AtomicCounted>>();
+
+        let list = List::<_, AtomicCounted>::new().prepend(1).prepend(2);
+        let tail = list.tail();
+        let handle = thread::spawn(move || tail.prepend(3).iter().sum::<i32>());
+        assert_eq!(handle.join().unwrap(), 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+    }
+
+    #[test]
+    fn long_list_drop() {
+        let mut list = List::<_, Boxed>::new();
+        for i in 0..100_000 {
+            list.push(i);
+        }
+        drop(list);
+
+        let mut list = List::<_, Counted>::new();
+        for i in 0..100_000 {
+            list = list.prepend(i);
+        }
+        let shared = list.tail();
+        drop(list);
+        assert_eq!(shared.peek(), Some(&99_998));
+    }
+
+    #[test]
+    fn concurrent_drop() {
+        // the threads race to drop the last pointer to the shared suffix, the winner has to free it iteratively
+        for _ in 0..4 {
+            let mut suffix = List::<_, AtomicCounted>::new();
+            for i in 0..100_000 {
+                suffix = suffix.prepend(i);
+            }
+            let barrier = Barrier::new(4);
+            thread::scope(|s| {
+                for i in 0..4 {
+                    let list = suffix.prepend(i);
+                    let barrier = &barrier;
+                    s.spawn(move || {
+                        barrier.wait();
+                        drop(list);
+                    });
+                }
+                drop(suffix);
+            });
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::third::List;
+
+/// Undo/redo history built on the persistent `List` from `third`.
+/// Both stacks are `List`s with the most recent state at the front, so moving a state from one to the other
+/// is a `pop_front` and a `push_front`. The states themselves are typically persistent `List`s as well:
+/// every edit `prepend`s to or takes the `tail` of the previous state, so storing all of them costs
+/// barely more than storing the last one.
+pub struct History<T> {
+    // the current state is the first element, below it are the ones `undo` can go back to
+    undo: List<T>,
+    // states `redo` can go forward to, the next one first
+    redo: List<T>,
+}
+
+impl<T: Clone> History<T> {
+    pub fn new(initial: T) -> Self {
+        History {
+            undo: List::new().prepend(initial),
+            redo: List::new(),
+        }
+    }
+
+    pub fn current(&self) -> &T {
+        // `undo` always holds at least the current state
+        self.undo.head().unwrap()
+    }
+
+    /// Makes `state` the current state. This starts a new branch, so everything that could be redone is dropped.
+    pub fn push_state(&mut self, state: T) {
+        self.undo.push_front(state);
+        self.redo = List::new();
+    }
+
+    /// Goes back to the previous state and returns it, or `None` if there is nothing to undo
+    pub fn undo(&mut self) -> Option<&T> {
+        if self.undo.len() < 2 {
+            return None;
+        }
+        let state = self.undo.pop_front()?;
+        self.redo.push_front(state);
+        self.undo.head()
+    }
+
+    /// Goes forward to the most recently undone state and returns it, or `None` if there is nothing to redo
+    pub fn redo(&mut self) -> Option<&T> {
+        let state = self.redo.pop_front()?;
+        self.undo.push_front(state);
+        self.undo.head()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.undo.len() > 1
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::History;
+    use crate::third::List;
+
+    #[test]
+    fn basics() {
+        let mut history = History::new(0);
+        assert_eq!(history.current(), &0);
+        assert!(!history.can_undo());
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), None);
+
+        history.push_state(1);
+        history.push_state(2);
+        assert_eq!(history.current(), &2);
+
+        assert_eq!(history.undo(), Some(&1));
+        assert_eq!(history.undo(), Some(&0));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.current(), &0);
+        assert!(history.can_redo());
+
+        assert_eq!(history.redo(), Some(&1));
+        assert_eq!(history.current(), &1);
+
+        // a new state drops the rest of the redo stack
+        history.push_state(3);
+        assert!(!history.can_redo());
+        assert_eq!(history.redo(), None);
+        assert_eq!(history.undo(), Some(&1));
+        assert_eq!(history.undo(), Some(&0));
+    }
+
+    #[test]
+    fn shared_states() {
+        // a text editor where the text is stored backwards, so typing is a `prepend` and backspace is a `tail`
+        let mut history = History::new(List::new());
+        for c in "abc".chars() {
+            let next = history.current().prepend(c);
+            history.push_state(next);
+        }
+        let backspace = history.current().tail();
+        history.push_state(backspace);
+        assert_eq!(history.current().reversed().to_vec(), vec!['a', 'b']);
+
+        let after_backspace = history.current().clone();
+        assert_eq!(
+            history.undo().unwrap().reversed().to_vec(),
+            vec!['a', 'b', 'c']
+        );
+        // every state points into the same `Node`s, so the whole history only has 3 of them
+        assert!(history.current().shares_structure(&after_backspace));
+        assert_eq!(history.current().shared_suffix_len(&after_backspace), 2);
+
+        assert!(history.redo() == Some(&after_backspace));
+        assert_eq!(history.undo().unwrap().len(), 3);
+        assert_eq!(history.undo().unwrap().len(), 2);
+        assert_eq!(history.undo().unwrap().len(), 1);
+        assert_eq!(history.undo().unwrap().len(), 0);
+        assert!(history.undo().is_none());
+    }
+}
@@ -0,0 +1,76 @@
+//! The heap behind `second::kmerge` and `fifth::kmerge`. Both only differ in how a `Node` is taken off
+//! the front of their `List`, so that is passed in by the caller, while the heads are compared through `Source`.
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// A sorted `List` that can take part in a merge
+pub(crate) trait Source {
+    type Elem: Ord;
+
+    fn head(&self) -> Option<&Self::Elem>;
+}
+
+pub(crate) struct Heap<L>(BinaryHeap<Run<L>>);
+
+impl<L: Source> Heap<L> {
+    pub(crate) fn new(lists: Vec<L>) -> Self {
+        Heap(
+            lists
+                .into_iter()
+                .enumerate()
+                // empty `List`s have no head to compare, so they never enter the heap
+                .filter(|(_, list)| list.head().is_some())
+                .map(|(index, list)| Run { list, index })
+                .collect(),
+        )
+    }
+
+    /// Takes the smallest head off its `List` with `pop`, which is O(log k) for k `List`s
+    pub(crate) fn pop_with<R>(&mut self, pop: impl FnOnce(&mut L) -> Option<R>) -> Option<R> {
+        let mut run = self.0.pop()?;
+        let node = pop(&mut run.list);
+        // put the `List` back if it still has elements, so its new head competes with the others
+        if run.list.head().is_some() {
+            self.0.push(run);
+        }
+        node
+    }
+}
+
+// a non-empty `List` taking part in a merge, along with its position in the input
+struct Run<L> {
+    list: L,
+    index: usize,
+}
+
+impl<L: Source> Run<L> {
+    fn head(&self) -> &L::Elem {
+        // `Run`s are only created from and put back into the heap with non-empty `List`s
+        self.list.head().unwrap()
+    }
+}
+
+// `BinaryHeap` is a max-heap, so the ordering is reversed to get the smallest head on top;
+// ties are broken by the index, so earlier `List`s win and the merge is stable
+impl<L: Source> Ord for Run<L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .head()
+            .cmp(self.head())
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl<L: Source> PartialOrd for Run<L> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<L: Source> PartialEq for Run<L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<L: Source> Eq for Run<L> {}
@@ -1,7 +1,15 @@
 pub mod fifth;
 pub mod first;
 pub mod fourth;
+pub mod fourth_sync;
+pub mod fourth_weak;
+pub mod generic;
+pub mod history;
+mod kmerge;
+pub mod lru;
+mod macros;
 pub mod second;
 pub mod silly1;
 pub mod silly2;
 pub mod third;
+pub mod third_sync;
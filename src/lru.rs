@@ -0,0 +1,198 @@
+use crate::fourth::{List, NodeHandle};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Least recently used cache: a `HashMap` finds the entry of a key in O(1), and the deque from `fourth` keeps
+/// the keys in order of use, the most recent at the front. Each entry holds a `NodeHandle` to its key's `Node`,
+/// so moving a key to the front is also O(1), which is exactly what a doubly linked list is good at.
+/// When the cache is full, the key at the back is the one that was not used for the longest time, so it is evicted.
+pub struct LruCache<K, V> {
+    map: HashMap<K, Entry<K, V>>,
+    // the keys, most recently used first
+    order: List<K>,
+    capacity: usize,
+}
+
+struct Entry<K, V> {
+    value: V,
+    handle: NodeHandle<K>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// Creates a cache holding at most `capacity` entries. Panics if `capacity` is 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "An LruCache needs room for at least one entry"
+        );
+        LruCache {
+            map: HashMap::with_capacity(capacity),
+            order: List::new(),
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Returns the value for `key` and marks it as the most recently used
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let entry = self.map.get_mut(key)?;
+        Self::touch(&mut self.order, entry);
+        Some(&entry.value)
+    }
+
+    /// Same as `get`, but the value can be changed
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let entry = self.map.get_mut(key)?;
+        Self::touch(&mut self.order, entry);
+        Some(&mut entry.value)
+    }
+
+    /// Returns the value for `key` without changing the order of use
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|entry| &entry.value)
+    }
+
+    /// Inserts or updates the value for `key`, making it the most recently used.
+    /// Returns the old value if the key was already present. Otherwise, if the cache was full,
+    /// the least recently used entry is evicted to make room.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(entry) = self.map.get_mut(&key) {
+            Self::touch(&mut self.order, entry);
+            return Some(std::mem::replace(&mut entry.value, value));
+        }
+
+        if self.map.len() == self.capacity {
+            let evicted = self.order.pop_back().unwrap();
+            self.map.remove(&evicted);
+        }
+        let handle = self.order.push_front_handle(key.clone());
+        self.map.insert(key, Entry { value, handle });
+        None
+    }
+
+    /// Removes the entry for `key`, returning its value
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.map.remove(key)?;
+        self.order.remove(&entry.handle);
+        Some(entry.value)
+    }
+
+    /// Moves the entry's key to the front by relinking its `Node`, so its handle stays valid
+    fn touch(order: &mut List<K>, entry: &mut Entry<K, V>) {
+        let moved = order.move_to_front(&entry.handle);
+        debug_assert!(moved, "every entry's key is in the order list");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LruCache;
+
+    #[test]
+    fn basics() {
+        let mut cache = LruCache::new(2);
+        assert!(cache.is_empty());
+        assert_eq!(cache.get(&"a"), None);
+
+        assert_eq!(cache.put("a", 1), None);
+        assert_eq!(cache.put("b", 2), None);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"a"), Some(&1));
+
+        // "b" is the least recently used, so it gets evicted
+        assert_eq!(cache.put("c", 3), None);
+        assert!(!cache.contains(&"b"));
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert_eq!(cache.get(&"c"), Some(&3));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.capacity(), 2);
+    }
+
+    #[test]
+    fn update_and_peek() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+
+        // updating counts as a use
+        assert_eq!(cache.put("a", 10), Some(1));
+        cache.put("c", 3);
+        assert_eq!(cache.peek(&"a"), Some(&10));
+        assert_eq!(cache.peek(&"b"), None);
+
+        // peeking does not, so "a" is evicted next
+        cache.put("d", 4);
+        assert_eq!(cache.peek(&"a"), None);
+        assert_eq!(cache.peek(&"c"), Some(&3));
+
+        *cache.get_mut(&"c").unwrap() += 1;
+        cache.put("e", 5);
+        assert_eq!(cache.peek(&"c"), Some(&4));
+        assert_eq!(cache.peek(&"d"), None);
+    }
+
+    #[test]
+    fn handle_survives_get() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+
+        // a hit relinks the same `Node`, so the handle stored in the entry still points to it
+        let handle = cache.map[&"a"].handle.clone();
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert!(handle.is_alive());
+        assert_eq!(handle.upgrade_value(), Some("a"));
+        assert_eq!(cache.order.peek_front().as_deref(), Some(&"a"));
+        cache.order.debug_validate();
+    }
+
+    #[test]
+    fn remove() {
+        let mut cache = LruCache::new(3);
+        cache.put(1, "one");
+        cache.put(2, "two");
+        cache.put(3, "three");
+
+        assert_eq!(cache.remove(&2), Some("two"));
+        assert_eq!(cache.remove(&2), None);
+        assert_eq!(cache.len(), 2);
+
+        // there is room again, so nothing is evicted
+        cache.put(4, "four");
+        assert!(cache.contains(&1));
+        cache.put(5, "five");
+        assert!(!cache.contains(&1));
+        assert!(cache.contains(&3));
+    }
+
+    #[test]
+    fn capacity_one() {
+        let mut cache = LruCache::new(1);
+        cache.put(1, 1);
+        cache.put(2, 2);
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&2), Some(&2));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_capacity() {
+        LruCache::<i32, i32>::new(0);
+    }
+}
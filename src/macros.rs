@@ -0,0 +1,93 @@
+/// Builds a list holding the given elements in the written order, similar to `vec!`.
+/// Without a prefix, a `second::List` is created, other modules can be chosen by naming them first,
+/// e.g. `list![1, 2, 3]` or `list![fifth: 1, 2, 3]`.
+#[macro_export]
+macro_rules! list {
+    (first: $($elem:expr),* $(,)?) => {
+        $crate::first::List::from_slice(&[$($elem),*])
+    };
+    (second: $($elem:expr),* $(,)?) => {
+        $crate::second::List::from([$($elem),*])
+    };
+    // persistent `List`s are built back to front by `prepend`ing to the previous version
+    (third: $($elem:expr),* $(,)?) => {
+        [$($elem),*]
+            .into_iter()
+            .rev()
+            .fold($crate::third::List::new(), |list, elem| list.prepend(elem))
+    };
+    // `list![fourth:]` pushes nothing, so `mut` would be unused
+    (fourth: $($elem:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut list = $crate::fourth::List::new();
+        $(list.push_back($elem);)*
+        list
+    }};
+    (fifth: $($elem:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut list = $crate::fifth::List::new();
+        $(list.push($elem);)*
+        list
+    }};
+    ($($elem:expr),* $(,)?) => {
+        $crate::list![second: $($elem),*]
+    };
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn first() {
+        let mut list = list![first: 1, 2, 3];
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn second() {
+        let list = list![1, 2, 3];
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let list = list![second: 1, 2, 3,];
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let list: crate::second::List<i32> = list![];
+        assert_eq!(list.peek(), None);
+    }
+
+    #[test]
+    fn third() {
+        let list = list![third: 1, 2, 3];
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn fourth() {
+        let mut list = list![fourth: 1, 2, 3];
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), None);
+
+        let list: crate::fourth::List<i32> = list![fourth:];
+        assert!(list.peek_front().is_none());
+    }
+
+    #[test]
+    fn fifth() {
+        let mut list = list![fifth: 1, 2, 3];
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+
+        let list: crate::fifth::List<i32> = list![fifth:];
+        assert_eq!(list.peek(), None);
+    }
+}
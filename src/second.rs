@@ -1,5 +1,14 @@
+use crate::kmerge::{Heap, Source};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::mem;
+use std::ops::Add;
+
 pub struct List<T> {
     head: Link<T>,
+    // number of `Node`s, kept up to date by every operation that links or unlinks them
+    len: usize,
 }
 
 /// As `Link` is basically an `Option`, use it instead of reinventing the wheel
@@ -12,28 +21,41 @@ struct Node<T> {
 
 impl<T> List<T> {
     pub fn new() -> Self {
-        Self { head: None }
+        Self { head: None, len: 0 }
     }
 
-    // `into_iter` consumes the original collection, hence type parameter `<T>` and taking `self` by value
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
     // `iter` returns a type for iterating over the collection, the head is passed by reference to `Iter`,
     // along with taking self by a const reference (`&self`)
     // because of lifetime elision rules, the compiler assumes that `self` must live as long as `Iter`, which is correct
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             // `as_deref` takes the underlying value as a reference, instead of having to use
             // `as_ref`, `map` and an assortment of `*`s and `&`s to get the desired type (namely `|node| &**node`)
             next: self.head.as_deref(),
+            len: self.len,
         }
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut {
             next: self.head.as_deref_mut(),
+            len: self.len,
+        }
+    }
+
+    /// Returns an `Iterator` over each pair of adjacent elements, e.g. `(1, 2), (2, 3)` for `1 -> 2 -> 3`
+    pub fn iter_pairs(&self) -> Pairs<'_, T> {
+        Pairs {
+            first: self.head.as_deref(),
+            len: self.len.saturating_sub(1),
         }
     }
 
@@ -46,12 +68,14 @@ impl<T> List<T> {
         });
 
         self.head = Some(new_node);
+        self.len += 1;
     }
 
     pub fn pop(&mut self) -> Option<T> {
         // use `map` to apply a function to the inner value if it is available, i.e. `Some(v)`
         self.head.take().map(|node| {
             self.head = node.next;
+            self.len -= 1;
             node.elem
         })
     }
@@ -66,6 +90,320 @@ impl<T> List<T> {
         // use `as_mut` to get a mutable reference to the `Option`'s internal value
         self.head.as_mut().map(|node| &mut node.elem)
     }
+
+    /// Returns a reference to the element at the given index, walking the `List` from the front
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
+
+    /// Consumes the `List`, producing a new one with `f` applied to every element, in the same order
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> List<U> {
+        self.filter_map(|elem| Some(f(elem)))
+    }
+
+    /// Consumes the `List`, keeping only the elements `f` returns `true` for.
+    /// The type of the elements stays the same, so the original `Node`s are reused.
+    pub fn filter(mut self, f: impl FnMut(&T) -> bool) -> Self {
+        self.retain(f);
+        self
+    }
+
+    /// Consumes the `List`, producing a new one from the elements `f` returns `Some` for
+    pub fn filter_map<U>(mut self, mut f: impl FnMut(T) -> Option<U>) -> List<U> {
+        let mut list = List::new();
+        // the `Node`s are built front to back, so keep track of the `None` at the end of the new `List`
+        let mut tail = &mut list.head;
+        while let Some(elem) = self.pop() {
+            if let Some(elem) = f(elem) {
+                tail = &mut tail.insert(Box::new(Node { elem, next: None })).next;
+                list.len += 1;
+            }
+        }
+        list
+    }
+
+    /// Returns an adapter that prints the elements separated by `sep`, e.g. `list.display_with(", ")`
+    pub fn display_with<'a>(&'a self, sep: &'a str) -> DisplayWith<'a, T> {
+        DisplayWith { list: self, sep }
+    }
+
+    /// Converts the `List` into a `Vec`, keeping the order of the elements
+    pub fn into_vec(self) -> Vec<T> {
+        self.into_iter().collect()
+    }
+
+    /// Moves all elements of `other` to the end of this `List`, leaving `other` empty.
+    /// The `Node`s are relinked, not reallocated, but finding the end of this `List` is still O(n).
+    pub fn append(&mut self, other: &mut Self) {
+        // walk to the `None` at the end of the `List`, which is where the other `List` gets attached
+        let mut cur_link = &mut self.head;
+        while let Some(node) = cur_link {
+            cur_link = &mut node.next;
+        }
+        *cur_link = other.head.take();
+        self.len += mem::take(&mut other.len);
+    }
+
+    /// Splits the `List` in two at the given index, returning everything from index `at` onwards,
+    /// while this `List` keeps the first `at` elements. Panics if `at` is greater than the length.
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        let link = self
+            .link_mut(at)
+            .expect("Cannot split off at a nonexistent index");
+        // the rest of the `List` is moved out by `take`, leaving `None` as the new end of this `List`
+        let head = link.take();
+        let rest = List {
+            head,
+            len: self.len - at,
+        };
+        self.len = at;
+        rest
+    }
+
+    /// Moves the first `n` elements to the back of the `List` by relinking their `Node`s.
+    /// Panics if `n` is greater than the length.
+    pub fn rotate_left(&mut self, n: usize) {
+        let mut rest = self.split_off(n);
+        // the first `n` `Node`s go after the rest, then the rotated `List` takes the place of `self`
+        rest.append(self);
+        mem::swap(self, &mut rest);
+    }
+
+    /// Inserts an element at the given index, shifting everything after it by one.
+    /// If `at` is greater than the length, the element is handed back in an `Err`.
+    pub fn insert(&mut self, at: usize, elem: T) -> Result<(), T> {
+        match self.link_mut(at) {
+            Some(link) => {
+                // the new `Node` takes over the rest of the `List` from the `Link` it replaces
+                let new_node = Box::new(Node {
+                    elem,
+                    next: link.take(),
+                });
+                *link = Some(new_node);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(elem),
+        }
+    }
+
+    /// Removes and returns the element at the given index, or `None` if it is out of bounds
+    pub fn remove(&mut self, at: usize) -> Option<T> {
+        let link = self.link_mut(at)?;
+        // same as `pop`, but on a `Link` in the middle of the `List`
+        let node = link.take()?;
+        *link = node.next;
+        self.len -= 1;
+        Some(node.elem)
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, unlinking and dropping the rest in a single pass
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let mut cur_link = &mut self.head;
+        loop {
+            match cur_link {
+                // replace the `Link` pointing to the rejected `Node` with the one after it,
+                // which drops the `Node` (its `next` has been taken, so it does not recurse)
+                Some(node) if !f(&node.elem) => {
+                    *cur_link = node.next.take();
+                    self.len -= 1;
+                }
+                Some(node) => cur_link = &mut node.next,
+                None => break,
+            }
+        }
+    }
+
+    /// Returns an `Iterator` that lazily removes and yields the elements for which `f` returns `true`.
+    /// Elements that are not yielded stay in the `List`, even if the `Iterator` is dropped early.
+    pub fn extract_if<F>(&mut self, f: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf {
+            cur_link: Some(&mut self.head),
+            len: &mut self.len,
+            f,
+        }
+    }
+
+    /// Removes consecutive elements for which `same_bucket` returns `true`, keeping the first one of each run.
+    /// Same as `Vec::dedup_by`, `same_bucket` gets the element to be removed first, then the one it is compared to.
+    pub fn dedup_by(&mut self, mut same_bucket: impl FnMut(&mut T, &mut T) -> bool) {
+        let mut cur = self.head.as_deref_mut();
+        while let Some(node) = cur {
+            // unlink the `Node`s after the current one as long as they are duplicates of it
+            while let Some(next) = node.next.as_mut() {
+                if !same_bucket(&mut next.elem, &mut node.elem) {
+                    break;
+                }
+                node.next = next.next.take();
+                self.len -= 1;
+            }
+            cur = node.next.as_deref_mut();
+        }
+    }
+
+    /// Sorts the `List` with a stable merge sort, which relinks the `Node`s instead of moving the elements
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// If `compare` `panic`s, every element is kept, but their order is unspecified, like with `slice::sort_by`
+    pub fn sort_by(&mut self, mut compare: impl FnMut(&T, &T) -> Ordering) {
+        // bottom-up merge sort: every chain being worked on lives in the guard, never in a local,
+        // so if `compare` `panic`s, the guard can link all of them back into the `List` when it is dropped
+        let mut sort = SortGuard {
+            input: self.head.take(),
+            runs: Vec::new(),
+            carry: None,
+            merged: None,
+            list: self,
+        };
+        while let Some(mut node) = sort.input.take() {
+            sort.input = node.next.take();
+            sort.carry = Some(node);
+            // like adding 1 to a binary number: `runs[i]` is either empty or holds 2^i sorted elements,
+            // and merging two runs of the same size carries the result over to the next slot
+            let mut i = 0;
+            while let Some(run) = sort.runs.get_mut(i).filter(|run| run.is_some()) {
+                // `run` holds earlier elements than `carry`, so it goes on the left to keep the sort stable
+                Self::merge(run, &mut sort.carry, &mut sort.merged, &mut compare);
+                sort.carry = sort.merged.take();
+                i += 1;
+            }
+            if i == sort.runs.len() {
+                sort.runs.push(None);
+            }
+            sort.runs[i] = sort.carry.take();
+        }
+        // merge the leftover runs, from the shortest (latest elements) to the longest (earliest elements)
+        for run in &mut sort.runs {
+            Self::merge(run, &mut sort.carry, &mut sort.merged, &mut compare);
+            sort.carry = sort.merged.take();
+        }
+        // the sorted chain is in `carry`, dropping the guard puts it back into the `List`
+    }
+
+    pub fn sort_by_key<K: Ord>(&mut self, mut f: impl FnMut(&T) -> K) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Merges two sorted `List`s into one sorted `List` in O(n) by relinking their `Node`s.
+    /// On ties, elements of `self` come before the ones of `other`.
+    pub fn merge_sorted(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        self.merge_sorted_by(other, T::cmp)
+    }
+
+    pub fn merge_sorted_by(
+        mut self,
+        mut other: Self,
+        mut compare: impl FnMut(&T, &T) -> Ordering,
+    ) -> Self {
+        // the chains stay in `List`s the whole time, so if `compare` `panic`s, they are dropped without recursion
+        let mut merged = List::new();
+        Self::merge(
+            &mut self.head,
+            &mut other.head,
+            &mut merged.head,
+            &mut compare,
+        );
+        merged.len = mem::take(&mut self.len) + mem::take(&mut other.len);
+        merged
+    }
+
+    // moves the `Node`s of two sorted chains onto the empty `merged` one, on ties the `Node` from `left` goes first,
+    // which keeps the sort stable. `compare` is only called while every `Node` is in one of the three chains,
+    // so none of them is lost if it `panic`s
+    fn merge<F>(left: &mut Link<T>, right: &mut Link<T>, merged: &mut Link<T>, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // the `None` at the end of the merged chain, where the next `Node` gets attached
+        let mut tail = merged;
+        loop {
+            let source = match (left.as_deref(), right.as_deref()) {
+                (Some(l), Some(r)) if compare(&r.elem, &l.elem) == Ordering::Less => &mut *right,
+                (Some(_), Some(_)) => &mut *left,
+                _ => break,
+            };
+            // pop the `Node` off the chain it came from and attach it to the end of the merged one
+            let mut node = source.take().unwrap();
+            *source = node.next.take();
+            tail = &mut tail.insert(node).next;
+        }
+        // at most one of the chains is left, which is already sorted
+        *tail = left.take().or_else(|| right.take());
+    }
+
+    // walks to the `Link` at the given index, which can be the `None` right after the last element,
+    // returns `None` if the `List` ends before reaching it
+    fn link_mut(&mut self, at: usize) -> Option<&mut Link<T>> {
+        let mut cur_link = &mut self.head;
+        for _ in 0..at {
+            // `?` bails out if the `List` is too short
+            cur_link = &mut cur_link.as_mut()?.next;
+        }
+        Some(cur_link)
+    }
+
+    // moving whole `Node`s around instead of their elements means no reallocation happens
+    fn push_node(&mut self, mut node: Box<Node<T>>) {
+        node.next = self.head.take();
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    fn pop_node(&mut self) -> Option<Box<Node<T>>> {
+        self.head.take().map(|mut node| {
+            self.head = node.next.take();
+            self.len -= 1;
+            node
+        })
+    }
+}
+
+// methods that need to compare elements live in a separate `impl` block, so the rest of the `List`
+// can still be used with `T`s that are not `PartialEq`
+impl<T: PartialEq> List<T> {
+    pub fn contains(&self, x: &T) -> bool {
+        self.position(x).is_some()
+    }
+
+    /// Removes consecutive repeated elements, so a sorted `List` ends up with no duplicates
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Returns the index of the first element equal to `x`
+    pub fn position(&self, x: &T) -> Option<usize> {
+        let mut cur = self.head.as_deref();
+        let mut index = 0;
+        while let Some(node) = cur {
+            if node.elem == *x {
+                return Some(index);
+            }
+            index += 1;
+            cur = node.next.as_deref();
+        }
+        None
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> Drop for List<T> {
@@ -78,6 +416,111 @@ impl<T> Drop for List<T> {
     }
 }
 
+/// Holds every chain `sort_by` is working on. When sorting is done, only `carry` is left, holding the whole
+/// sorted chain; if `compare` `panic`ked, the `Node`s can be spread over all of them, so they are linked one after
+/// the other, and the `List` gets back every element, in some order. The `len` of the `List` is never touched.
+struct SortGuard<'a, T> {
+    list: &'a mut List<T>,
+    // the `Node`s not sorted yet
+    input: Link<T>,
+    runs: Vec<Link<T>>,
+    carry: Link<T>,
+    merged: Link<T>,
+}
+
+impl<T> Drop for SortGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut head = None;
+        let pieces = [&mut self.carry, &mut self.merged, &mut self.input]
+            .into_iter()
+            .chain(&mut self.runs);
+        for piece in pieces {
+            let Some(piece) = piece.take() else {
+                continue;
+            };
+            if head.is_none() {
+                // after a successful sort, this is the only piece, so nothing has to be walked
+                head = Some(piece);
+                continue;
+            }
+            // put the piece in front of the others, which needs its last `next`
+            let mut piece = Some(piece);
+            let mut link = &mut piece;
+            while link.is_some() {
+                link = &mut link.as_mut().unwrap().next;
+            }
+            *link = head.take();
+            head = piece;
+        }
+        self.list.head = head;
+    }
+}
+
+/// Prints the elements front to back in arrow style: `1 -> 2 -> 3`
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display_with(" -> "), f)
+    }
+}
+
+pub struct DisplayWith<'a, T> {
+    list: &'a List<T>,
+    sep: &'a str,
+}
+
+impl<T: fmt::Display> fmt::Display for DisplayWith<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, elem) in self.list.iter().enumerate() {
+            // the separator goes between the elements, not after the last one
+            if index > 0 {
+                f.write_str(self.sep)?;
+            }
+            // forward the `Formatter` so flags like width and precision apply to each element
+            elem.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// `a + b` concatenates the two `List`s, consuming both
+impl<T> Add for List<T> {
+    type Output = List<T>;
+    fn add(mut self, mut other: Self) -> Self::Output {
+        self.append(&mut other);
+        self
+    }
+}
+
+// as `push` adds to the front, the elements are pushed in reverse to keep their order
+impl<T> From<Vec<T>> for List<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut list = List::new();
+        for elem in vec.into_iter().rev() {
+            list.push(elem);
+        }
+        list
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for List<T> {
+    fn from(array: [T; N]) -> Self {
+        let mut list = List::new();
+        for elem in array.into_iter().rev() {
+            list.push(elem);
+        }
+        list
+    }
+}
+
+// `into_iter` consumes the original collection, hence type parameter `<T>` and taking `self` by value
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
 // tuple struct for holding the `List` converted into an `Iterator`
 // these structs are useful for wrapping values simply (newtype)
 pub struct IntoIter<T>(List<T>);
@@ -89,12 +532,32 @@ impl<T> Iterator for IntoIter<T> {
         // simply access the underlying `List` and `pop` the front element, which already returns an `Option<T>`
         self.0.pop()
     }
+
+    // the `List` knows its length, so the number of remaining elements is exact
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+// `ExactSizeIterator` provides `len()`, based on `size_hint`, which must be exact for it to be correct
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+// once an `Iterator` returns `None`, it keeps doing so, which lets `fuse()` skip its extra bookkeeping
+impl<T> FusedIterator for IntoIter<T> {}
+
+// prints the elements that are still left to yield
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
 }
 
 // struct for handling `iter()`, which holds a reference to the `Node` it needs to yield next, or `None`, if exhausted
 pub struct Iter<'a, T> {
     // as this structs holds a reference, it must name the lifetime that reference needs to be valid for
     next: Option<&'a Node<T>>,
+    // number of elements left to yield
+    len: usize,
 }
 
 impl<'a, T> Iterator for Iter<'a, T> {
@@ -104,13 +567,40 @@ impl<'a, T> Iterator for Iter<'a, T> {
         // unwrap the value contained by the current node, alongside with moving to the next one
         self.next.map(|node| {
             self.next = node.next.as_deref();
+            self.len -= 1;
             &node.elem
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+// `#[derive(Clone)]` would require `T: Clone`, even though only the references are copied
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            next: self.next,
+            len: self.len,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // walk a copy, so the original `Iterator` is not advanced
+        f.debug_list().entries(self.clone()).finish()
+    }
 }
 
 pub struct IterMut<'a, T> {
     next: Option<&'a mut Node<T>>,
+    len: usize,
 }
 
 impl<'a, T> Iterator for IterMut<'a, T> {
@@ -120,15 +610,181 @@ impl<'a, T> Iterator for IterMut<'a, T> {
         // this also ensures that the reference to the actual element is singleton, as the `Option` is `None` after `take`
         self.next.take().map(|node| {
             self.next = node.next.as_deref_mut();
+            self.len -= 1;
             &mut node.elem
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+// only the first `Node` of the next pair is stored, the second one is always reachable through its `next`
+pub struct Pairs<'a, T> {
+    first: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Pairs<'a, T> {
+    type Item = (&'a T, &'a T);
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.first?;
+        // if there is no second `Node`, `first` becomes `None` as well, so the `Iterator` stays exhausted
+        self.first = first.next.as_deref();
+        let second = self.first?;
+        self.len -= 1;
+        Some((&first.elem, &second.elem))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Pairs<'_, T> {}
+
+impl<T> FusedIterator for Pairs<'_, T> {}
+
+// holds a reference to the next `Link` to inspect, so the `List` is fully linked up between calls to `next`,
+// which makes it valid no matter when the `Iterator` gets dropped or `f` panics
+pub struct ExtractIf<'a, T, F> {
+    cur_link: Option<&'a mut Link<T>>,
+    len: &'a mut usize,
+    f: F,
+}
+
+impl<'a, T, F> Iterator for ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // `take` the reference out of `self`, so it can be moved further down the `List`
+            // at the end of the `List`, `self.cur_link` stays `None` so the `Iterator` is exhausted
+            let cur_link = self.cur_link.take()?;
+            // the element is checked with a short-lived borrow, so the `Link` can still be modified afterwards
+            if (self.f)(&mut cur_link.as_mut()?.elem) {
+                // same as `pop`, the reference stays on the same `Link`, which now holds the next `Node`
+                let node = cur_link.take()?;
+                *cur_link = node.next;
+                *self.len -= 1;
+                self.cur_link = Some(cur_link);
+                return Some(node.elem);
+            }
+            self.cur_link = cur_link.as_mut().map(|node| &mut node.next);
+        }
+    }
+}
+
+/// A FIFO variant of `List`. Keeping a pointer to the last `Node` would mean aliasing a `Box` that is
+/// owned by its predecessor, which cannot be expressed without `unsafe` (see `fifth` for that version).
+/// Instead, two stacks are used: elements are popped from `front` and pushed onto `back`, and whenever
+/// `front` runs dry, the `Node`s of `back` are relinked onto it, which reverses them into FIFO order.
+/// `push_back` is O(1) in the worst case, but `pop` is only O(1) amortized: every `Node` is moved at most once,
+/// yet the single `pop` that empties `front` relinks all of `back`, so that one call is O(n).
+/// When every operation has to be O(1), use `fifth::List`, which does keep a tail pointer.
+/// `front` is only ever empty when the whole `Queue` is, so peeking never has to look at `back`.
+pub struct Queue<T> {
+    front: List<T>,
+    back: List<T>,
+}
+
+impl<T> Queue<T> {
+    pub fn new() -> Self {
+        Queue {
+            front: List::new(),
+            back: List::new(),
+        }
+    }
+
+    /// Pushes to the front, same as `List::push`
+    pub fn push(&mut self, elem: T) {
+        self.front.push(elem);
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        if self.front.peek().is_none() {
+            // empty `Queue`, the new element is also the first one
+            self.front.push(elem);
+        } else {
+            self.back.push(elem);
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let elem = self.front.pop();
+        if self.front.peek().is_none() {
+            // restore the invariant by moving every `Node` of `back` over, which reverses their order
+            while let Some(node) = self.back.pop_node() {
+                self.front.push_node(node);
+            }
+        }
+        elem
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.front.peek()
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.front.peek_mut()
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Merges any number of sorted `List`s into a single sorted sequence. Only the heads of the `List`s are
+/// compared, using a `BinaryHeap` that always has the smallest head on top, so yielding an element is
+/// O(log k) for k `List`s. Equal elements are yielded in the order of the `List`s they came from.
+pub fn kmerge<T: Ord>(lists: Vec<List<T>>) -> KMerge<T> {
+    KMerge(Heap::new(lists))
+}
+
+/// Same as `kmerge`, but relinks the `Node`s into a new `List` instead of yielding the elements
+pub fn kmerge_into_list<T: Ord>(lists: Vec<List<T>>) -> List<T> {
+    let mut merge = kmerge(lists);
+    let mut list = List::new();
+    // keep a reference to the `None` at the end of the result, so each `Node` can be attached in O(1)
+    let mut tail = &mut list.head;
+    while let Some(node) = merge.0.pop_with(List::pop_node) {
+        // `insert` puts the `Node` into the `Option` and returns a reference to it
+        tail = &mut tail.insert(node).next;
+        list.len += 1;
+    }
+    list
+}
+
+pub struct KMerge<T>(Heap<List<T>>);
+
+impl<T: Ord> Iterator for KMerge<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_with(List::pop_node).map(|node| node.elem)
+    }
+}
+
+impl<T: Ord> Source for List<T> {
+    type Elem = T;
+
+    fn head(&self) -> Option<&T> {
+        self.peek()
+    }
 }
 
 #[cfg(test)]
 mod test {
 
-    use super::List;
+    use super::{kmerge, kmerge_into_list, List, Queue};
 
     #[test]
     fn basics() {
@@ -159,6 +815,109 @@ mod test {
         assert_eq!(list.pop(), None);
     }
 
+    #[test]
+    fn len() {
+        let mut list = List::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        list.push(1);
+        list.push(2);
+        assert_eq!(list.len(), 2);
+        list.pop();
+        assert_eq!(list.len(), 1);
+
+        list.append(&mut List::from([2, 3, 4, 5, 6]));
+        assert_eq!(list.len(), 6);
+        let rest = list.split_off(4);
+        assert_eq!((list.len(), rest.len()), (4, 2));
+        list = list + rest;
+        assert_eq!(list.len(), 6);
+
+        list.insert(6, 6).unwrap();
+        list.remove(0);
+        assert_eq!(list.len(), 6);
+
+        list.retain(|&elem| elem != 2);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.extract_if(|&mut elem| elem == 3).count(), 1);
+        assert_eq!(list.len(), 4);
+        list.dedup();
+        assert_eq!(list.len(), 3);
+
+        let list = list.merge_sorted(List::from([0, 5])).map(|elem| elem + 1);
+        assert_eq!(list.len(), 5);
+        let list = list.filter_map(|elem| (elem > 1).then_some(elem));
+        assert_eq!(list.len(), 4);
+
+        let list = kmerge_into_list(vec![list, List::from([0])]);
+        assert_eq!(list.len(), 5);
+        assert!(!list.is_empty());
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut list = List::from([1, 2, 3]);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.len(), 0);
+        // fused: keeps returning `None`
+        assert_eq!(iter.next(), None);
+
+        let mut iter = list.iter_mut();
+        iter.next();
+        assert_eq!(iter.len(), 2);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn iter_clone_debug() {
+        let list = List::from([1, 2, 3]);
+
+        let mut iter = list.iter();
+        iter.next();
+        let cloned = iter.clone();
+        assert_eq!(iter.next(), Some(&2));
+        // the clone is independent of the original
+        assert_eq!(cloned.copied().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(format!("{:?}", iter), "[3]");
+
+        let mut into_iter = list.into_iter();
+        into_iter.next();
+        assert_eq!(format!("{:?}", into_iter), "[2, 3]");
+    }
+
+    #[test]
+    fn iter_pairs() {
+        let list = List::<i32>::new();
+        assert_eq!(list.iter_pairs().next(), None);
+
+        let list = List::from([1]);
+        assert_eq!(list.iter_pairs().len(), 0);
+        assert_eq!(list.iter_pairs().next(), None);
+
+        let list = List::from([1, 4, 9, 16]);
+        let mut pairs = list.iter_pairs();
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs.next(), Some((&1, &4)));
+        assert_eq!(pairs.next(), Some((&4, &9)));
+        assert_eq!(pairs.next(), Some((&9, &16)));
+        assert_eq!(pairs.next(), None);
+        assert_eq!(pairs.next(), None);
+
+        let differences: Vec<_> = list.iter_pairs().map(|(a, b)| b - a).collect();
+        assert_eq!(differences, vec![3, 5, 7]);
+    }
+
     #[test]
     fn peek() {
         let mut list = List::new();
@@ -173,7 +932,9 @@ mod test {
         assert_eq!(list.peek_mut(), Some(&mut 3));
 
         // test whether the value can actually be mutated
-        list.peek_mut().map(|value| *value = 42);
+        if let Some(value) = list.peek_mut() {
+            *value = 42;
+        }
 
         assert_eq!(list.peek(), Some(&42));
         assert_eq!(list.pop(), Some(42));
@@ -218,4 +979,553 @@ mod test {
         assert_eq!(iter.next(), Some(&mut 2));
         assert_eq!(iter.next(), Some(&mut 1));
     }
+
+    #[test]
+    fn conversions() {
+        let list = List::from(vec![1, 2, 3]);
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let list = List::from([1, 2, 3]);
+        assert_eq!(list.into_vec(), vec![1, 2, 3]);
+
+        let list = List::<i32>::from([]);
+        assert_eq!(list.into_vec(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn add() {
+        let list = List::from([1, 2]) + List::from([3]) + List::new() + List::from([4, 5]);
+        assert_eq!(list.into_vec(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(List::<i32>::new().to_string(), "");
+        assert_eq!(List::from([1]).to_string(), "1");
+        assert_eq!(List::from([1, 2, 3]).to_string(), "1 -> 2 -> 3");
+
+        let list = List::from([1.5, 2.25]);
+        assert_eq!(list.display_with(", ").to_string(), "1.5, 2.25");
+        assert_eq!(format!("{:.1}", list.display_with("|")), "1.5|2.2");
+    }
+
+    #[test]
+    fn map_filter() {
+        let list = List::from([1, 2, 3, 4]);
+        let list = list.map(|elem| elem * 10);
+        assert_eq!(list.into_vec(), vec![10, 20, 30, 40]);
+
+        let list = List::from([1, 2, 3, 4]).filter(|elem| elem % 2 == 0);
+        assert_eq!(list.into_vec(), vec![2, 4]);
+
+        let list = List::from(["1", "two", "3"]).filter_map(|elem| elem.parse::<i32>().ok());
+        assert_eq!(list.into_vec(), vec![1, 3]);
+
+        let list = List::<i32>::new().map(|elem| elem.to_string());
+        assert_eq!(list.peek(), None);
+    }
+
+    #[test]
+    fn get() {
+        let mut list = List::new();
+        assert_eq!(list.get(0), None);
+        assert_eq!(list.get_mut(0), None);
+
+        list.push(3);
+        list.push(2);
+        list.push(1);
+
+        assert_eq!(list.get(0), Some(&1));
+        assert_eq!(list.get(2), Some(&3));
+        assert_eq!(list.get(3), None);
+
+        if let Some(value) = list.get_mut(1) {
+            *value = 42;
+        }
+        assert_eq!(list.get(1), Some(&42));
+        assert_eq!(list.get_mut(3), None);
+    }
+
+    #[test]
+    fn contains_position() {
+        let mut list = List::new();
+        assert!(!list.contains(&1));
+        assert_eq!(list.position(&1), None);
+
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        list.push(2);
+
+        assert!(list.contains(&1));
+        assert!(!list.contains(&4));
+        // the first match wins
+        assert_eq!(list.position(&2), Some(0));
+        assert_eq!(list.position(&3), Some(3));
+        assert_eq!(list.position(&4), None);
+    }
+
+    #[test]
+    fn retain() {
+        let mut list = List::new();
+        list.retain(|_| false);
+        assert_eq!(list.peek(), None);
+
+        for elem in (1..=8).rev() {
+            list.push(elem);
+        }
+
+        // removes from the front, the middle and the back
+        list.retain(|&elem| elem % 2 == 0 && elem != 8);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), Some(&6));
+        assert_eq!(iter.next(), None);
+
+        list.retain(|_| false);
+        assert_eq!(list.peek(), None);
+    }
+
+    #[test]
+    fn extract_if() {
+        let mut list = List::new();
+        for elem in (1..=6).rev() {
+            list.push(elem);
+        }
+
+        let evens: Vec<_> = list.extract_if(|elem| *elem % 2 == 0).collect();
+        assert_eq!(evens, vec![2, 4, 6]);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), None);
+
+        // dropping the `Iterator` early leaves the rest in the `List`
+        assert_eq!(list.extract_if(|_| true).next(), Some(1));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(5));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn extract_if_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut list = List::new();
+        for elem in (1..=4).rev() {
+            list.push(elem);
+        }
+
+        // panic in the middle of extracting, after 1 has already been removed
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            list.extract_if(|elem| {
+                assert!(*elem < 3);
+                true
+            })
+            .for_each(drop);
+        }));
+        assert!(result.is_err());
+
+        // the `List` is still intact and usable
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn dedup() {
+        let mut list = List::new();
+        list.dedup();
+        assert_eq!(list.peek(), None);
+
+        for elem in [3, 3, 2, 1, 1, 1, 2, 2] {
+            list.push(elem);
+        }
+        list.dedup();
+        // only consecutive duplicates are removed
+        let elems: Vec<_> = list.iter().copied().collect();
+        assert_eq!(elems, vec![2, 1, 2, 3]);
+    }
+
+    #[test]
+    fn dedup_by() {
+        let mut list = List::new();
+        for elem in ["Baz", "bar", "foo", "Foo"] {
+            list.push(elem.to_string());
+        }
+        list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        let elems: Vec<_> = list.iter().map(String::as_str).collect();
+        assert_eq!(elems, vec!["Foo", "bar", "Baz"]);
+
+        // `same_bucket` can modify the kept element, e.g. to count the removed ones
+        let mut list = List::new();
+        for elem in [(2, 1), (2, 1), (1, 1), (1, 1), (1, 1)] {
+            list.push(elem);
+        }
+        list.dedup_by(|next, kept| {
+            let same = next.0 == kept.0;
+            if same {
+                kept.1 += next.1;
+            }
+            same
+        });
+        assert_eq!(list.pop(), Some((1, 3)));
+        assert_eq!(list.pop(), Some((2, 2)));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn sort() {
+        let mut list = List::new();
+        list.sort();
+        assert_eq!(list.peek(), None);
+
+        for elem in [5, 1, 4, 2, 3, 1] {
+            list.push(elem);
+        }
+        list.sort();
+        let sorted: Vec<_> = list.iter().copied().collect();
+        assert_eq!(sorted, vec![1, 1, 2, 3, 4, 5]);
+
+        list.sort_by(|a, b| b.cmp(a));
+        let sorted: Vec<_> = list.iter().copied().collect();
+        assert_eq!(sorted, vec![5, 4, 3, 2, 1, 1]);
+
+        // distance from 3, ties keep the descending order from the previous sort
+        list.sort_by_key(|&elem: &i32| (elem - 3).abs());
+        let sorted: Vec<_> = list.iter().copied().collect();
+        assert_eq!(sorted, vec![3, 4, 2, 5, 1, 1]);
+    }
+
+    #[test]
+    fn sort_stable() {
+        let mut list = List::new();
+        for (key, tag) in [(2, 'd'), (1, 'c'), (2, 'b'), (1, 'a')] {
+            list.push(Keyed(key, tag));
+        }
+        // `Keyed` only compares the key, so the tags show whether equal elements kept their order
+        list.sort();
+        let tags: Vec<_> = list.iter().map(|Keyed(_, tag)| *tag).collect();
+        assert_eq!(tags, vec!['a', 'c', 'b', 'd']);
+    }
+
+    #[test]
+    fn sort_long() {
+        let mut list = List::new();
+        for elem in 0..100_000 {
+            list.push(elem);
+        }
+        list.sort();
+        assert!(list.iter().copied().eq(0..100_000));
+    }
+
+    #[test]
+    fn sort_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        // long enough that dropping the chains recursively would overflow the stack
+        let mut list = List::new();
+        for elem in 0..100_000 {
+            list.push(elem);
+        }
+        let mut comparisons = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            list.sort_by(|a: &i32, b| {
+                comparisons += 1;
+                assert!(comparisons < 500_000);
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+
+        // every element is still there, in some order
+        assert_eq!(list.len(), 100_000);
+        assert_eq!(list.iter().count(), 100_000);
+        let mut elems: Vec<_> = list.iter().copied().collect();
+        elems.sort();
+        assert!(elems.into_iter().eq(0..100_000));
+
+        // and the `List` can still be sorted afterwards
+        list.sort();
+        assert!(list.iter().copied().eq(0..100_000));
+
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| list.sort_by(|_, _| panic!())));
+        assert!(result.is_err());
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().count(), 2);
+    }
+
+    #[test]
+    fn merge_sorted() {
+        let mut left = List::new();
+        let mut right = List::new();
+        for elem in [7, 5, 3, 1] {
+            left.push(elem);
+        }
+        for elem in [8, 6, 4, 2, 0] {
+            right.push(elem);
+        }
+
+        let merged = left.merge_sorted(right);
+        assert!(merged.iter().copied().eq(0..9));
+
+        // merging with an empty `List` leaves the other one as it was
+        let merged = merged.merge_sorted(List::new());
+        assert!(merged.iter().copied().eq(0..9));
+        let merged = List::new().merge_sorted(merged);
+        assert!(merged.iter().copied().eq(0..9));
+    }
+
+    #[test]
+    fn merge_sorted_by() {
+        let mut left = List::new();
+        let mut right = List::new();
+        left.push(Keyed(1, 'a'));
+        left.push(Keyed(2, 'a'));
+        right.push(Keyed(1, 'b'));
+        right.push(Keyed(3, 'b'));
+
+        // descending order, with elements of `self` winning ties
+        let merged = left.merge_sorted_by(right, |a, b| b.cmp(a));
+        let elems: Vec<_> = merged.iter().map(|Keyed(key, tag)| (*key, *tag)).collect();
+        assert_eq!(elems, vec![(3, 'b'), (2, 'a'), (1, 'a'), (1, 'b')]);
+    }
+
+    #[test]
+    fn append() {
+        let mut list = List::new();
+        let mut other = List::new();
+
+        // appending empty `List`s is a no-op
+        list.append(&mut other);
+        assert_eq!(list.peek(), None);
+
+        other.push(2);
+        other.push(1);
+        list.append(&mut other);
+        assert_eq!(other.peek(), None);
+
+        list.push(0);
+        other.push(4);
+        other.push(3);
+        list.append(&mut other);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), None);
+
+        // `other` is still usable after being drained
+        other.push(5);
+        assert_eq!(other.pop(), Some(5));
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list = List::new();
+        list.push(3);
+        list.push(2);
+        list.push(1);
+
+        let mut rest = list.split_off(1);
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+        assert_eq!(rest.pop(), Some(2));
+        assert_eq!(rest.pop(), Some(3));
+        assert_eq!(rest.pop(), None);
+
+        // splitting at the ends
+        list.push(2);
+        list.push(1);
+        let rest = list.split_off(2);
+        assert_eq!(rest.peek(), None);
+        let mut rest = list.split_off(0);
+        assert_eq!(list.peek(), None);
+        assert_eq!(rest.pop(), Some(1));
+        assert_eq!(rest.pop(), Some(2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_off_out_of_bounds() {
+        let mut list = List::new();
+        list.push(1);
+        list.split_off(2);
+    }
+
+    #[test]
+    fn rotate_left() {
+        let mut list = List::new();
+        list.rotate_left(0);
+        assert_eq!(list.peek(), None);
+
+        for elem in (1..=5).rev() {
+            list.push(elem);
+        }
+        list.rotate_left(2);
+        let elems: Vec<_> = list.iter().copied().collect();
+        assert_eq!(elems, vec![3, 4, 5, 1, 2]);
+
+        // rotating by 0 or the full length is a no-op
+        list.rotate_left(0);
+        list.rotate_left(5);
+        let elems: Vec<_> = list.iter().copied().collect();
+        assert_eq!(elems, vec![3, 4, 5, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_left_out_of_bounds() {
+        let mut list = List::new();
+        list.push(1);
+        list.rotate_left(2);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut list = List::new();
+        assert_eq!(list.remove(0), None);
+        assert_eq!(list.insert(1, 1), Err(1));
+
+        assert_eq!(list.insert(0, 2), Ok(()));
+        assert_eq!(list.insert(0, 0), Ok(()));
+        assert_eq!(list.insert(1, 1), Ok(()));
+        // inserting right after the last element appends
+        assert_eq!(list.insert(3, 3), Ok(()));
+        assert_eq!(list.insert(5, 5), Err(5));
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(list.remove(4), None);
+        assert_eq!(list.remove(3), Some(3));
+        assert_eq!(list.remove(1), Some(1));
+        assert_eq!(list.remove(0), Some(0));
+        assert_eq!(list.remove(0), Some(2));
+        assert_eq!(list.remove(0), None);
+    }
+
+    #[test]
+    fn queue() {
+        let mut queue = Queue::new();
+        assert_eq!(queue.pop(), None);
+        assert_eq!(queue.peek(), None);
+
+        queue.push_back(1);
+        queue.push_back(2);
+        queue.push_back(3);
+
+        assert_eq!(queue.peek(), Some(&1));
+        assert_eq!(queue.pop(), Some(1));
+
+        // interleave pushes with the relinking of `back` onto `front`
+        queue.push_back(4);
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), Some(3));
+        queue.push_back(5);
+        assert_eq!(queue.pop(), Some(4));
+
+        // the stack API still works on the front
+        queue.push(6);
+        assert_eq!(queue.peek(), Some(&6));
+        if let Some(value) = queue.peek_mut() {
+            *value = 42;
+        }
+        assert_eq!(queue.pop(), Some(42));
+
+        // check exhaustion
+        assert_eq!(queue.pop(), Some(5));
+        assert_eq!(queue.pop(), None);
+        assert_eq!(queue.peek(), None);
+    }
+
+    // builds a `List` that yields the elements in the given order
+    fn sorted_run(elems: &[i32]) -> List<i32> {
+        let mut list = List::new();
+        for &elem in elems.iter().rev() {
+            list.push(elem);
+        }
+        list
+    }
+
+    #[test]
+    fn kmerge_iter() {
+        assert_eq!(kmerge(Vec::<List<i32>>::new()).next(), None);
+
+        let lists = vec![
+            sorted_run(&[1, 4, 7]),
+            List::new(),
+            sorted_run(&[2, 5, 8, 9]),
+            sorted_run(&[3, 6]),
+        ];
+        let merged: Vec<_> = kmerge(lists).collect();
+        assert_eq!(merged, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    // only compares the key, so equal elements can still be told apart by their tag
+    #[derive(Debug)]
+    struct Keyed(i32, char);
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Keyed {}
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn kmerge_stable() {
+        // equal keys come out in the order of the `List`s they came from
+        let mut first = List::new();
+        first.push(Keyed(2, 'a'));
+        first.push(Keyed(1, 'a'));
+        let mut second = List::new();
+        second.push(Keyed(2, 'b'));
+        second.push(Keyed(1, 'b'));
+
+        let tags: Vec<_> = kmerge(vec![second, first])
+            .map(|Keyed(_, tag)| tag)
+            .collect();
+        assert_eq!(tags, vec!['b', 'a', 'b', 'a']);
+    }
+
+    #[test]
+    fn kmerge_into() {
+        let lists = vec![
+            sorted_run(&[1, 3, 5]),
+            sorted_run(&[2, 4]),
+            sorted_run(&[0]),
+        ];
+        let mut list = kmerge_into_list(lists);
+        for expected in 0..6 {
+            assert_eq!(list.pop(), Some(expected));
+        }
+        assert_eq!(list.pop(), None);
+    }
 }
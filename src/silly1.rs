@@ -2,11 +2,14 @@
 // while we are in the middle of them.
 // with `go_left` and `go_right`, we can traverse the list in both ways by just `push`ing and
 // `pop`ping elements from one `Stack` to the other!
+// only used by the tests, which is fine for an example
+#[allow(dead_code)]
 struct List<T> {
     left: Stack<T>,
     right: Stack<T>,
 }
 
+#[allow(dead_code)]
 impl<T> List<T> {
     fn new() -> Self {
         List {
@@ -106,6 +109,12 @@ impl<T> Stack<T> {
     }
 }
 
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Drop for Stack<T> {
     fn drop(&mut self) {
         let mut cur_link = self.head.take();
@@ -1,3 +1,7 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::ControlFlow;
 use std::rc::Rc;
 
 /// This is how memory should look when using this version of `List` (persistent `List`).
@@ -9,9 +13,8 @@ use std::rc::Rc;
 ///               |
 /// list3 -> X ---+
 ///
-/// If thread safety is needed, every `Rc` just needs to be replaced with `Arc`, and it will be safe.
+/// If thread safety is needed, every `Rc` just needs to be replaced with `Arc`, and it will be safe (see `third_sync`).
 /// This is because `Arc` = `Rc`, it is just a bit slower because it uses `Atomic`s instead of `Cell`s for reference counting
-
 pub struct List<T> {
     head: Link<T>,
 }
@@ -19,9 +22,14 @@ pub struct List<T> {
 /// Use `Rc` for reference counting; the underlying `Node` is freed when the last reference gets dropped
 type Link<T> = Option<Rc<Node<T>>>;
 
+// `Clone` is what `Rc::make_mut` uses to copy a shared `Node`, cloning `next` only bumps a reference count
+#[derive(Clone)]
 struct Node<T> {
     elem: T,
     next: Link<T>,
+    // length of the sublist starting at this `Node`, as `Node`s are immutable once created,
+    // it never changes, no matter how many `List`s end up sharing them
+    len: usize,
 }
 
 impl<T> List<T> {
@@ -29,12 +37,39 @@ impl<T> List<T> {
         List { head: None }
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    /// Creates a `List` of `n` clones of `elem`
+    pub fn repeat(elem: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut list = List::new();
+        for _ in 0..n {
+            list.push_front(elem.clone());
+        }
+        list
+    }
+
+    /// Creates a `List` of `n` elements, where the element at index `i` is `f(i)`.
+    /// `f` is called in index order, so the elements are collected first, as the `List` is built back to front.
+    pub fn from_fn(n: usize, f: impl FnMut(usize) -> T) -> Self {
+        let elems: Vec<T> = (0..n).map(f).collect();
+        List::from(elems)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             next: self.head.as_deref(),
         }
     }
 
+    /// Returns an `Iterator` over every suffix of the `List`, from the whole `List` down to the empty one,
+    /// like `tails` in Haskell. Each suffix is a `List` pointing into this one, so nothing is copied.
+    pub fn suffixes(&self) -> Suffixes<'_, T> {
+        Suffixes {
+            next: Some(&self.head),
+        }
+    }
+
     /// Return a new `List` that has the provided element added to the front, the original `List` is still usable
     pub fn prepend(&self, elem: T) -> List<T> {
         List {
@@ -44,6 +79,7 @@ impl<T> List<T> {
                 // clone the `Option` holding the `Rc` pointing to the next element, which increments the reference count to it,
                 // so now there are 2 `List`s pointing to the same sublist, this one being the original
                 next: self.head.clone(),
+                len: self.len() + 1,
             })),
         }
     }
@@ -57,11 +93,590 @@ impl<T> List<T> {
         }
     }
 
+    /// Adds the element to the front of this `List`, like `prepend` without creating a new `List`.
+    /// Other `List`s are not affected, as they keep pointing to the old first `Node`.
+    pub fn push_front(&mut self, elem: T) {
+        let len = self.len() + 1;
+        self.head = Some(Rc::new(Node {
+            elem,
+            next: self.head.take(),
+            len,
+        }));
+    }
+
+    /// Removes the first element of this `List`, like `tail` without creating a new `List`.
+    /// If no other `List` points to the first `Node`, the element is moved out of it,
+    /// otherwise the `Node` has to stay intact for the others, so the element is cloned.
+    pub fn pop_front(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.head.take().map(|node| match Rc::try_unwrap(node) {
+            Ok(node) => {
+                self.head = node.next;
+                node.elem
+            }
+            Err(node) => {
+                self.head = node.next.clone();
+                node.elem.clone()
+            }
+        })
+    }
+
+    /// Splits the `List` into its first element and the rest, like the `x:xs` pattern in Haskell.
+    /// The rest shares every `Node` with this `List`.
+    pub fn uncons(&self) -> Option<(&T, List<T>)> {
+        self.head.as_ref().map(|node| {
+            (
+                &node.elem,
+                List {
+                    head: node.next.clone(),
+                },
+            )
+        })
+    }
+
+    /// Returns a new `List` with the elements in reverse order. Every `Node` points to the one after it,
+    /// so reversing changes all of them, which means nothing can be shared and all elements are cloned.
+    pub fn reversed(&self) -> List<T>
+    where
+        T: Clone,
+    {
+        // prepending while walking front to back puts the first element at the end
+        self.iter()
+            .fold(List::new(), |list, elem| list.prepend(elem.clone()))
+    }
+
+    /// Returns a new `List` with the first `n` elements. As the last copied `Node` has to point to nothing,
+    /// instead of the rest of this `List`, the whole prefix needs to be cloned.
+    pub fn take(&self, n: usize) -> List<T>
+    where
+        T: Clone,
+    {
+        // persistent `List`s are built back to front, so collect the prefix first to be able to walk it backwards
+        let prefix: Vec<&T> = self.iter().take(n).collect();
+        prefix
+            .into_iter()
+            .rev()
+            .fold(List::new(), |list, elem| list.prepend(elem.clone()))
+    }
+
+    /// Returns the `List` after skipping the first `n` elements (or an empty one, if there are fewer).
+    /// This is just a pointer to a `Node` in the middle, so it shares everything and allocates nothing.
+    pub fn drop(&self, n: usize) -> List<T> {
+        let mut cur = self.head.as_ref();
+        for _ in 0..n {
+            cur = cur.and_then(|node| node.next.as_ref());
+        }
+        List {
+            // `cloned` on `Option<&Rc>` clones the `Rc`, so only the reference count goes up
+            head: cur.cloned(),
+        }
+    }
+
+    /// Splits the `List` at `n`, returning `take(n)` and `drop(n)`: the prefix is cloned, the suffix is shared
+    pub fn split_at(&self, n: usize) -> (List<T>, List<T>)
+    where
+        T: Clone,
+    {
+        (self.take(n), self.drop(n))
+    }
+
+    /// Returns a new `List` with the element at `index` replaced by `value`, or `None` if it is out of bounds.
+    /// This is called path copying: only the `Node`s up to and including `index` are recreated,
+    /// as they are the ones whose `next` pointers lead to the changed `Node`, while the rest is shared.
+    pub fn update(&self, index: usize, value: T) -> Option<List<T>>
+    where
+        T: Clone,
+    {
+        let mut prefix = Vec::with_capacity(index);
+        let mut cur = self.head.as_deref();
+        for _ in 0..index {
+            let node = cur?;
+            prefix.push(&node.elem);
+            cur = node.next.as_deref();
+        }
+        let node = cur?;
+
+        // everything after the updated `Node` is shared with this `List`
+        let suffix = List {
+            head: node.next.clone(),
+        };
+        Some(
+            prefix
+                .into_iter()
+                .rev()
+                .fold(suffix.prepend(value), |list, elem| {
+                    list.prepend(elem.clone())
+                }),
+        )
+    }
+
+    /// Returns a new `List` with `elem` inserted before the first greater element, so a sorted `List` stays sorted.
+    /// Like `update`, only the `Node`s before the insertion point are copied, everything after it is shared.
+    pub fn insert_sorted(&self, elem: T) -> List<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut prefix = Vec::new();
+        let mut cur = self.head.as_ref();
+        // skip equal elements as well, so the new one ends up after them
+        while let Some(node) = cur.filter(|node| node.elem <= elem) {
+            prefix.push(&node.elem);
+            cur = node.next.as_ref();
+        }
+
+        let suffix = List { head: cur.cloned() };
+        prefix
+            .into_iter()
+            .rev()
+            .fold(suffix.prepend(elem), |list, elem| {
+                list.prepend(elem.clone())
+            })
+    }
+
+    /// Returns a new `List` with `f` applied to every element. The element type changes, so nothing can be shared.
+    /// Like everything else here, this is done with loops instead of recursion, so long `List`s are fine.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> List<U> {
+        let elems: Vec<U> = self.iter().map(f).collect();
+        elems
+            .into_iter()
+            .rev()
+            .fold(List::new(), |list, elem| list.prepend(elem))
+    }
+
+    /// Returns a new `List` combining the elements of both `List`s pairwise with `f`,
+    /// stopping at the end of the shorter one
+    pub fn zip_with<U, V>(&self, other: &List<U>, mut f: impl FnMut(&T, &U) -> V) -> List<V> {
+        let elems: Vec<V> = self
+            .iter()
+            .zip(other.iter())
+            .map(|(a, b)| f(a, b))
+            .collect();
+        elems
+            .into_iter()
+            .rev()
+            .fold(List::new(), |list, elem| list.prepend(elem))
+    }
+
+    /// Returns a new `List` with the elements `f` returns `true` for. The part after the last rejected
+    /// element is unchanged, so that suffix is shared, and only the kept elements before it are cloned.
+    pub fn filter(&self, mut f: impl FnMut(&T) -> bool) -> List<T>
+    where
+        T: Clone,
+    {
+        // kept elements before the last rejected one, these need to be copied
+        let mut kept = Vec::new();
+        // kept elements after the last rejected one, these might end up in the shared suffix
+        let mut pending = Vec::new();
+        let mut suffix = self.head.as_ref();
+
+        let mut cur = self.head.as_ref();
+        while let Some(node) = cur {
+            if f(&node.elem) {
+                pending.push(&node.elem);
+            } else {
+                kept.append(&mut pending);
+                suffix = node.next.as_ref();
+            }
+            cur = node.next.as_ref();
+        }
+
+        let suffix = List {
+            head: suffix.cloned(),
+        };
+        kept.into_iter()
+            .rev()
+            .fold(suffix, |list, elem| list.prepend(elem.clone()))
+    }
+
+    /// Returns whether the two `List`s share any `Node`s, i.e. they have a common tail in memory
+    pub fn shares_structure(&self, other: &List<T>) -> bool {
+        self.shared_suffix_len(other) > 0
+    }
+
+    /// Returns how many `Node`s at the end of the two `List`s are shared (the same `Rc`, not just equal elements)
+    pub fn shared_suffix_len(&self, other: &List<T>) -> usize {
+        let mut left = self.head.as_ref();
+        let mut right = other.head.as_ref();
+        // a shared `Node` is the same distance away from the end in both `List`s,
+        // so skip ahead in the longer one until both have the same length left
+        for _ in other.len()..self.len() {
+            left = left.and_then(|node| node.next.as_ref());
+        }
+        for _ in self.len()..other.len() {
+            right = right.and_then(|node| node.next.as_ref());
+        }
+
+        while let (Some(l), Some(r)) = (left, right) {
+            // once a `Node` is shared, everything after it is too, and its `len` tells how many there are
+            if Rc::ptr_eq(l, r) {
+                return l.len;
+            }
+            left = l.next.as_ref();
+            right = r.next.as_ref();
+        }
+        0
+    }
+
+    /// Returns the number of elements in O(1), by reading the length stored in the first `Node`
+    pub fn len(&self) -> usize {
+        self.head.as_ref().map_or(0, |node| node.len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns how many pointers (`List`s or other `Node`s) point to the first `Node`, or `None` if empty
+    pub fn head_strong_count(&self) -> Option<usize> {
+        self.head.as_ref().map(Rc::strong_count)
+    }
+
+    /// Returns an `Iterator` of `(index, strong_count)` pairs for every `Node`, which shows exactly which part
+    /// of the `List` is shared. The walk only uses references, so it does not change any of the counts.
+    pub fn strong_counts(&self) -> StrongCounts<'_, T> {
+        StrongCounts {
+            next: self.head.as_ref(),
+            index: 0,
+        }
+    }
+
+    /// Clones the elements into a `Vec`, keeping their order
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
     /// Returns a reference pointing to the first element
     pub fn head(&self) -> Option<&T> {
         // extract the element out of `Link`
         self.head.as_ref().map(|node| &node.elem)
     }
+
+    /// Returns a reference to the last element, walking the whole `List`
+    pub fn last(&self) -> Option<&T> {
+        let mut cur = self.head.as_deref()?;
+        while let Some(next) = cur.next.as_deref() {
+            cur = next;
+        }
+        Some(&cur.elem)
+    }
+
+    /// Returns a reference to the element at index `n`, walking the first `n` `Node`s
+    pub fn nth(&self, n: usize) -> Option<&T> {
+        let mut cur = self.head.as_deref();
+        for _ in 0..n {
+            cur = cur?.next.as_deref();
+        }
+        cur.map(|node| &node.elem)
+    }
+
+    /// Returns an adapter that prints several named `List`s, one per line, in the spirit of the diagram at the top:
+    /// a `List` is printed until it reaches a `Node` that was already printed on an earlier line, where it shows
+    /// which `List` it joins and at which index, e.g. `list2 -> X --+ list1[1]`
+    pub fn fmt_with_sharing<'a>(lists: &'a [(&'a str, &'a List<T>)]) -> WithSharing<'a, T> {
+        WithSharing { lists }
+    }
+
+    /// Counts the distinct `Node`s used by all the `lists`, next to the sum of their lengths,
+    /// which is how many `Node`s they would need without sharing
+    pub fn unique_node_count(lists: &[&List<T>]) -> NodeCount {
+        let mut seen = HashSet::new();
+        let mut total = 0;
+        for list in lists {
+            total += list.len();
+            let mut cur = list.head.as_ref();
+            // `insert` returns `false` for an already counted `Node`, and everything after it was counted too
+            while let Some(node) = cur.filter(|node| seen.insert(Rc::as_ptr(node))) {
+                cur = node.next.as_ref();
+            }
+        }
+        NodeCount {
+            unique: seen.len(),
+            total,
+        }
+    }
+
+    /// Returns a mutable reference to the first element. If the first `Node` is shared with other `List`s,
+    /// it is copied first (copy-on-write), so the others never see the change.
+    pub fn head_mut(&mut self) -> Option<&mut T>
+    where
+        T: Clone,
+    {
+        // `make_mut` clones the `Node` only if its reference count is above 1, otherwise it just hands it out
+        self.head.as_mut().map(|node| &mut Rc::make_mut(node).elem)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of bounds.
+    /// This is the in-place version of `update`: every shared `Node` on the path is copied,
+    /// while the ones only this `List` points to are reused as they are.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T>
+    where
+        T: Clone,
+    {
+        // check first, so no `Node`s are copied for nothing
+        if index >= self.len() {
+            return None;
+        }
+        let mut cur = &mut self.head;
+        for _ in 0..index {
+            cur = &mut Rc::make_mut(cur.as_mut()?).next;
+        }
+        cur.as_mut().map(|node| &mut Rc::make_mut(node).elem)
+    }
+
+    /// Calls `f` on the element at `index` through `get_mut`, returning whether it was in bounds
+    pub fn update_in_place(&mut self, index: usize, f: impl FnOnce(&mut T)) -> bool
+    where
+        T: Clone,
+    {
+        self.get_mut(index).map(f).is_some()
+    }
+
+    /// Left fold that can stop early: `f` returns `ControlFlow::Continue` with the new accumulator to keep
+    /// going, or `ControlFlow::Break` with the final result. The `Node`s are walked through plain references,
+    /// so no `Iter` is created and no reference counts are touched.
+    pub fn fold_while<B>(&self, init: B, mut f: impl FnMut(B, &T) -> ControlFlow<B, B>) -> B {
+        let mut acc = init;
+        let mut cur = self.head.as_deref();
+        while let Some(node) = cur {
+            match f(acc, &node.elem) {
+                ControlFlow::Continue(next) => acc = next,
+                ControlFlow::Break(result) => return result,
+            }
+            cur = node.next.as_deref();
+        }
+        acc
+    }
+
+    /// Left fold, combining the elements front to back: `f(f(f(init, a), b), c)`
+    pub fn fold<B>(&self, init: B, mut f: impl FnMut(B, &T) -> B) -> B {
+        self.fold_while(init, |acc, elem| ControlFlow::Continue(f(acc, elem)))
+    }
+
+    /// Right fold, combining the elements back to front: `f(a, f(b, f(c, init)))`.
+    /// The natural implementation is recursive, which would overflow the stack for long `List`s,
+    /// so the elements are collected onto a `Vec` first, and then popped off in reverse.
+    pub fn rfold<B>(&self, init: B, mut f: impl FnMut(&T, B) -> B) -> B {
+        let stack: Vec<&T> = self.iter().collect();
+        stack.into_iter().rev().fold(init, |acc, elem| f(elem, acc))
+    }
+
+    /// Returns whether any element matches the predicate, stopping at the first match
+    pub fn any(&self, mut f: impl FnMut(&T) -> bool) -> bool {
+        self.fold_while(false, |_, elem| {
+            if f(elem) {
+                ControlFlow::Break(true)
+            } else {
+                ControlFlow::Continue(false)
+            }
+        })
+    }
+
+    /// Returns whether all elements match the predicate, stopping at the first mismatch
+    pub fn all(&self, mut f: impl FnMut(&T) -> bool) -> bool {
+        !self.any(|elem| !f(elem))
+    }
+
+    /// Returns the index of the first element matching the predicate
+    pub fn position(&self, mut f: impl FnMut(&T) -> bool) -> Option<usize> {
+        let mut cur = self.head.as_deref();
+        let mut index = 0;
+        while let Some(node) = cur {
+            if f(&node.elem) {
+                return Some(index);
+            }
+            index += 1;
+            cur = node.next.as_deref();
+        }
+        None
+    }
+
+    /// Returns a reference to the first element matching the predicate
+    pub fn find(&self, mut f: impl FnMut(&T) -> bool) -> Option<&T> {
+        let mut cur = self.head.as_deref();
+        while let Some(node) = cur {
+            if f(&node.elem) {
+                return Some(&node.elem);
+            }
+            cur = node.next.as_deref();
+        }
+        None
+    }
+}
+
+impl<T: PartialEq> List<T> {
+    pub fn contains(&self, elem: &T) -> bool {
+        self.any(|other| other == elem)
+    }
+
+    /// Returns whether the first elements of this `List` are the elements of `prefix`
+    pub fn starts_with(&self, prefix: &List<T>) -> bool {
+        prefix.len() <= self.len() && self.iter().zip(prefix.iter()).all(|(a, b)| a == b)
+    }
+
+    /// Returns whether the last elements of this `List` are the elements of `suffix`.
+    /// Thanks to the stored lengths, the only candidate is the suffix of the same length,
+    /// and comparing it with `==` returns right away if `suffix` is literally that shared tail.
+    pub fn ends_with(&self, suffix: &List<T>) -> bool {
+        let Some(skip) = self.len().checked_sub(suffix.len()) else {
+            return false;
+        };
+        let mut cur = self.head.as_ref();
+        for _ in 0..skip {
+            cur = cur.and_then(|node| node.next.as_ref());
+        }
+        match (cur, suffix.head.as_ref()) {
+            // cheap path for the common case of asking about a `tail` or `drop` of this `List`
+            (Some(node), Some(other)) if Rc::ptr_eq(node, other) => true,
+            // fall back to comparing the elements, `PartialEq` still stops at the first shared `Node`
+            _ => List { head: cur.cloned() } == *suffix,
+        }
+    }
+}
+
+/// Structural equality: same elements in the same order. Walking stops as soon as both `List`s reach the
+/// same `Node`, as everything after it is shared, so `List`s that only differ in a short prefix are compared
+/// in O(prefix). Just like `Rc`'s own fast path, this assumes that every element is equal to itself,
+/// which does not hold for e.g. `f64::NAN`.
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // lengths are stored in the `Node`s, so different lengths are caught in O(1), and with equal lengths,
+        // a shared `Node` is guaranteed to be at the same position in both `List`s
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let mut left = self.head.as_ref();
+        let mut right = other.head.as_ref();
+        while let (Some(l), Some(r)) = (left, right) {
+            if Rc::ptr_eq(l, r) {
+                return true;
+            }
+            if l.elem != r.elem {
+                return false;
+            }
+            left = l.next.as_ref();
+            right = r.next.as_ref();
+        }
+        true
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, elem) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" -> ")?;
+            }
+            elem.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of `List::unique_node_count`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeCount {
+    /// Number of distinct `Node`s
+    pub unique: usize,
+    /// Sum of the lengths of the `List`s
+    pub total: usize,
+}
+
+impl NodeCount {
+    /// Number of `Node`s saved by sharing
+    pub fn saved(&self) -> usize {
+        self.total - self.unique
+    }
+}
+
+pub struct WithSharing<'a, T> {
+    lists: &'a [(&'a str, &'a List<T>)],
+}
+
+impl<T: fmt::Display> fmt::Display for WithSharing<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // every printed `Node` by address, along with the `List` it was printed in and its index there
+        let mut printed = HashMap::new();
+        for (line, &(name, list)) in self.lists.iter().enumerate() {
+            if line > 0 {
+                f.write_str("\n")?;
+            }
+            f.write_str(name)?;
+            if list.is_empty() {
+                f.write_str(" (empty)")?;
+                continue;
+            }
+
+            let mut cur = list.head.as_ref();
+            let mut index = 0;
+            while let Some(node) = cur {
+                if let Some((owner, at)) = printed.get(&Rc::as_ptr(node)) {
+                    // everything from here on was already printed, so just point at it
+                    write!(f, " --+ {owner}[{at}]")?;
+                    break;
+                }
+                write!(f, " -> {}", node.elem)?;
+                printed.insert(Rc::as_ptr(node), (name, index));
+                index += 1;
+                cur = node.next.as_ref();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Hashes the same way as a slice does (length, then every element), so `List`s that are equal hash the same,
+/// no matter which `Node`s they share
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for List<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut list = List::new();
+        // the `List` is built back to front, so the last element has to be added first
+        for elem in vec.into_iter().rev() {
+            list.push_front(elem);
+        }
+        list
+    }
+}
+
+impl<T: Clone> From<&[T]> for List<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut list = List::new();
+        for elem in slice.iter().rev() {
+            list.push_front(elem.clone());
+        }
+        list
+    }
+}
+
+/// Cloning a `List` only clones the pointer to the first `Node`, so both `List`s share every `Node`
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        List {
+            head: self.head.clone(),
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// `Drop` is required here as well so there is no recursive destructor problem
@@ -83,6 +698,119 @@ impl<T> Drop for List<T> {
     }
 }
 
+/// Serialized as a plain sequence of the elements, any sharing with other `List`s is lost
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for List<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for List<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(List::from)
+    }
+}
+
+/// A group of `List`s that are (de)serialized together: every `Node` is written once, no matter how many
+/// of the `List`s share it, and after deserializing, the `List`s share the same `Node`s again
+#[cfg(feature = "serde")]
+pub struct SharedLists<T>(pub Vec<List<T>>);
+
+// the wire format: `nodes` holds every element along with the index of its `next` `Node`,
+// which always comes before it, and `heads` holds the index of the first `Node` of each `List`
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct SharedNodesRef<'a, T> {
+    nodes: Vec<(&'a T, Option<usize>)>,
+    heads: Vec<Option<usize>>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SharedNodes<T> {
+    nodes: Vec<(T, Option<usize>)>,
+    heads: Vec<Option<usize>>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for SharedLists<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // `Node`s are identified by their address, which is the same for every `List` sharing them
+        let mut indices = HashMap::new();
+        let mut nodes = Vec::new();
+        let mut heads = Vec::with_capacity(self.0.len());
+        for list in &self.0 {
+            // collect the `Node`s that were not written yet, stopping at the first shared one
+            let mut new = Vec::new();
+            let mut next = None;
+            let mut cur = list.head.as_ref();
+            while let Some(node) = cur {
+                if let Some(&index) = indices.get(&Rc::as_ptr(node)) {
+                    next = Some(index);
+                    break;
+                }
+                new.push(node);
+                cur = node.next.as_ref();
+            }
+            // write them back to front, so every `Node` comes after the one it points to
+            for node in new.into_iter().rev() {
+                nodes.push((&node.elem, next));
+                next = Some(nodes.len() - 1);
+                indices.insert(Rc::as_ptr(node), nodes.len() - 1);
+            }
+            heads.push(next);
+        }
+        SharedNodesRef { nodes, heads }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for SharedLists<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        // only indices of already built `Node`s are valid, which also rules out cycles
+        fn link<T, E: Error>(built: &[Rc<Node<T>>], index: Option<usize>) -> Result<Link<T>, E> {
+            match index {
+                Some(index) => built
+                    .get(index)
+                    .cloned()
+                    .map(Some)
+                    .ok_or_else(|| E::custom(format!("invalid node index {index}"))),
+                None => Ok(None),
+            }
+        }
+
+        fn build<T, E: Error>(
+            built: &mut Vec<Rc<Node<T>>>,
+            SharedNodes { nodes, heads }: SharedNodes<T>,
+        ) -> Result<Vec<List<T>>, E> {
+            for (elem, next) in nodes {
+                let next = link(built, next)?;
+                built.push(Rc::new(Node {
+                    elem,
+                    len: next.as_ref().map_or(0, |node| node.len) + 1,
+                    next,
+                }));
+            }
+            heads
+                .into_iter()
+                .map(|head| link(built, head).map(|head| List { head }))
+                .collect()
+        }
+
+        let shared = SharedNodes::deserialize(deserializer)?;
+        let mut built = Vec::with_capacity(shared.nodes.len());
+        let lists = build(&mut built, shared);
+        // free the `Node`s no `List` uses back to front, so each one only ever frees itself
+        // instead of recursing into a long chain of unused `Node`s (also when the input was invalid)
+        while built.pop().is_some() {}
+        lists.map(SharedLists)
+    }
+}
+
 pub struct Iter<'a, T> {
     next: Option<&'a Node<T>>,
 }
@@ -98,9 +826,46 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+pub struct Suffixes<'a, T> {
+    // `None` once the empty suffix has been yielded
+    next: Option<&'a Link<T>>,
+}
+
+impl<'a, T> Iterator for Suffixes<'a, T> {
+    type Item = List<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let link = self.next?;
+        // after the empty suffix (a `None` link) there is nothing left
+        self.next = link.as_ref().map(|node| &node.next);
+        // cloning the `Link` only bumps the reference count of the `Node` it points to
+        Some(List { head: link.clone() })
+    }
+}
+
+pub struct StrongCounts<'a, T> {
+    next: Option<&'a Rc<Node<T>>>,
+    index: usize,
+}
+
+impl<T> Iterator for StrongCounts<'_, T> {
+    type Item = (usize, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_ref();
+            self.index += 1;
+            (self.index - 1, Rc::strong_count(node))
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::List;
+    use super::{List, NodeCount};
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
+    use std::ops::ControlFlow;
+    use std::rc::Rc;
 
     #[test]
     fn basics() {
@@ -124,6 +889,433 @@ mod test {
         assert_eq!(list.head(), None);
     }
 
+    #[test]
+    fn repeat_from_fn() {
+        let list = List::repeat('a', 3);
+        assert!(list.iter().eq(['a', 'a', 'a'].iter()));
+        assert_eq!(list.len(), 3);
+        assert!(List::repeat(1, 0).is_empty());
+
+        let mut calls = Vec::new();
+        let list = List::from_fn(4, |i| {
+            calls.push(i);
+            i * i
+        });
+        assert!(list.iter().eq([0, 1, 4, 9].iter()));
+        assert_eq!(list.len(), 4);
+        assert_eq!(calls, vec![0, 1, 2, 3]);
+        assert!(List::from_fn(0, |i| i).is_empty());
+    }
+
+    #[test]
+    fn vec_slice() {
+        let list = List::from(&[1, 2, 3][..]);
+        assert!(list.iter().eq([1, 2, 3].iter()));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+
+        let list = List::from(vec![String::from("a"), String::from("b")]);
+        assert_eq!(list.to_vec(), vec!["a", "b"]);
+        assert_eq!(list.len(), 2);
+
+        let empty: &[i32] = &[];
+        assert!(List::from(empty).is_empty());
+        assert_eq!(List::<i32>::new().to_vec(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn len() {
+        let list = List::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        let list = list.prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.len(), 3);
+        assert!(!list.is_empty());
+
+        // `List`s sharing a tail each know their own length
+        let other = list.tail().prepend(4).prepend(5);
+        assert_eq!(other.len(), 4);
+        assert_eq!(list.tail().len(), 2);
+        assert_eq!(list.tail().tail().tail().len(), 0);
+    }
+
+    #[test]
+    fn reversed() {
+        let list = List::<i32>::new();
+        assert!(list.reversed().is_empty());
+
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+        let reversed = list.reversed();
+        assert!(reversed.iter().eq([1, 2, 3].iter()));
+        assert_eq!(reversed.len(), 3);
+        // the original is untouched
+        assert!(list.iter().eq([3, 2, 1].iter()));
+    }
+
+    #[test]
+    fn take_drop() {
+        let list = List::new().prepend(4).prepend(3).prepend(2).prepend(1);
+
+        let taken = list.take(2);
+        assert!(taken.iter().eq([1, 2].iter()));
+        assert_eq!(taken.len(), 2);
+        assert!(list.take(0).is_empty());
+        assert_eq!(list.take(10).len(), 4);
+
+        let dropped = list.drop(2);
+        assert!(dropped.iter().eq([3, 4].iter()));
+        assert_eq!(dropped.len(), 2);
+        assert!(list.drop(4).is_empty());
+        assert!(list.drop(10).is_empty());
+
+        // `dropped` starts at the same `Node` as the original's third element, which is now pointed to
+        // by both the second `Node` and `dropped`
+        let third = list
+            .head
+            .as_ref()
+            .unwrap()
+            .next
+            .as_ref()
+            .unwrap()
+            .next
+            .as_ref()
+            .unwrap();
+        assert!(Rc::ptr_eq(dropped.head.as_ref().unwrap(), third));
+        assert_eq!(Rc::strong_count(third), 2);
+
+        drop(dropped);
+        assert_eq!(Rc::strong_count(third), 1);
+    }
+
+    #[test]
+    fn split_at() {
+        let list = List::new().prepend(4).prepend(3).prepend(2).prepend(1);
+
+        let (prefix, suffix) = list.split_at(2);
+        assert!(prefix.iter().eq([1, 2].iter()));
+        assert!(suffix.iter().eq([3, 4].iter()));
+
+        // every `Node` of the suffix is the original one
+        let mut original = list.drop(2).head.clone();
+        let mut shared = suffix.head.clone();
+        while let (Some(a), Some(b)) = (original, shared) {
+            assert!(Rc::ptr_eq(&a, &b));
+            original = a.next.clone();
+            shared = b.next.clone();
+        }
+        // the prefix is a copy
+        assert!(!prefix.shares_structure(&list));
+
+        let (prefix, suffix) = list.split_at(0);
+        assert!(prefix.is_empty());
+        assert!(Rc::ptr_eq(
+            suffix.head.as_ref().unwrap(),
+            list.head.as_ref().unwrap()
+        ));
+
+        let (prefix, suffix) = list.split_at(10);
+        assert_eq!(prefix.len(), 4);
+        assert!(suffix.is_empty());
+    }
+
+    #[test]
+    fn update() {
+        let list = List::new().prepend(4).prepend(3).prepend(2).prepend(1);
+
+        let updated = list.update(1, 20).unwrap();
+        assert!(updated.iter().eq([1, 20, 3, 4].iter()));
+        assert_eq!(updated.len(), 4);
+        // the original is untouched
+        assert!(list.iter().eq([1, 2, 3, 4].iter()));
+
+        // the `Node`s after the updated one are shared, the ones before it are copies
+        assert!(Rc::ptr_eq(
+            updated.drop(2).head.as_ref().unwrap(),
+            list.drop(2).head.as_ref().unwrap()
+        ));
+        assert!(!Rc::ptr_eq(
+            updated.head.as_ref().unwrap(),
+            list.head.as_ref().unwrap()
+        ));
+
+        let updated = list.update(3, 40).unwrap();
+        assert!(updated.iter().eq([1, 2, 3, 40].iter()));
+        assert!(list.update(4, 50).is_none());
+        assert!(List::new().update(0, 1).is_none());
+    }
+
+    #[test]
+    fn copy_on_write() {
+        let mut list = List::new().prepend(3).prepend(2).prepend(1);
+        let before = Rc::as_ptr(list.head.as_ref().unwrap());
+
+        // nothing is shared, so the `Node` is changed where it is
+        *list.head_mut().unwrap() = 10;
+        assert!(list.iter().eq([10, 2, 3].iter()));
+        assert_eq!(Rc::as_ptr(list.head.as_ref().unwrap()), before);
+
+        let other = list.tail().prepend(4);
+        // the second `Node` is shared with `other`, so it is copied along with the first one
+        assert!(list.update_in_place(1, |elem| *elem *= 10));
+        assert!(list.iter().eq([10, 20, 3].iter()));
+        assert!(other.iter().eq([4, 2, 3].iter()));
+        assert_eq!(Rc::as_ptr(list.head.as_ref().unwrap()), before);
+        // the third `Node` was not on the path, so it is still shared
+        assert!(list.shares_structure(&other));
+        assert_eq!(list.shared_suffix_len(&other), 1);
+
+        assert!(!list.update_in_place(3, |elem| *elem = 0));
+        assert_eq!(list.get_mut(3), None);
+        assert_eq!(List::<i32>::new().head_mut(), None);
+        assert_eq!(other.head_strong_count(), Some(1));
+    }
+
+    #[test]
+    fn push_pop_front() {
+        let mut list = List::new();
+        assert_eq!(list.pop_front(), None);
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.len(), 1);
+
+        list.push_front(4);
+        let shared = list.drop(0);
+        // the first `Node` is shared, so popping leaves it (and its element) for `shared`
+        assert_eq!(list.pop_front(), Some(4));
+        assert!(shared.iter().eq([4, 1].iter()));
+        assert_eq!(shared.head_strong_count(), Some(1));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+        assert!(shared.iter().eq([4, 1].iter()));
+    }
+
+    #[test]
+    fn uncons() {
+        let list = List::new().prepend(2).prepend(1);
+
+        let (head, rest) = list.uncons().unwrap();
+        assert_eq!(head, &1);
+        assert!(rest.iter().eq([2].iter()));
+        assert!(Rc::ptr_eq(
+            rest.head.as_ref().unwrap(),
+            list.tail().head.as_ref().unwrap()
+        ));
+
+        let (head, rest) = rest.uncons().unwrap();
+        assert_eq!(head, &2);
+        assert!(rest.uncons().is_none());
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let list = List::new()
+            .insert_sorted(3)
+            .insert_sorted(1)
+            .insert_sorted(4);
+        assert!(list.iter().eq([1, 3, 4].iter()));
+
+        let inserted = list.insert_sorted(2);
+        assert!(inserted.iter().eq([1, 2, 3, 4].iter()));
+        assert_eq!(inserted.len(), 4);
+        // the original is untouched, and everything from the insertion point on is shared
+        assert!(list.iter().eq([1, 3, 4].iter()));
+        assert_eq!(inserted.shared_suffix_len(&list), 2);
+
+        assert!(list.insert_sorted(0).iter().eq([0, 1, 3, 4].iter()));
+        assert_eq!(list.insert_sorted(0).shared_suffix_len(&list), 3);
+        assert!(list.insert_sorted(5).iter().eq([1, 3, 4, 5].iter()));
+        assert!(list.insert_sorted(3).iter().eq([1, 3, 3, 4].iter()));
+    }
+
+    #[test]
+    fn map() {
+        let list = List::new().prepend(3).prepend(2).prepend(1);
+        let mapped = list.map(|elem| elem.to_string());
+        assert!(mapped.iter().eq(["1", "2", "3"].iter()));
+        assert_eq!(mapped.len(), 3);
+        assert!(List::<i32>::new().map(|elem| elem + 1).is_empty());
+    }
+
+    #[test]
+    fn zip_with() {
+        let numbers = List::new().prepend(3).prepend(2).prepend(1);
+        let words = List::new().prepend("b").prepend("a");
+
+        let zipped = numbers.zip_with(&words, |n, w| format!("{}{}", w, n));
+        assert!(zipped.iter().eq(["a1", "b2"].iter()));
+        assert_eq!(zipped.len(), 2);
+
+        let sums = numbers.zip_with(&numbers.tail(), |a, b| a + b);
+        assert!(sums.iter().eq([3, 5].iter()));
+
+        assert!(numbers
+            .zip_with(&List::<i32>::new(), |a, b| a + b)
+            .is_empty());
+    }
+
+    #[test]
+    fn filter() {
+        let list = List::new().prepend(4).prepend(3).prepend(2).prepend(1);
+
+        let odd = list.filter(|elem| elem % 2 == 1);
+        assert!(odd.iter().eq([1, 3].iter()));
+        assert_eq!(odd.len(), 2);
+        assert!(list.filter(|_| false).is_empty());
+
+        // 3 and 4 come after the last rejected element, so they are shared with the original
+        let filtered = list.filter(|&elem| elem != 2);
+        assert!(filtered.iter().eq([1, 3, 4].iter()));
+        assert!(Rc::ptr_eq(
+            filtered.drop(1).head.as_ref().unwrap(),
+            list.drop(2).head.as_ref().unwrap()
+        ));
+
+        // keeping everything shares the whole `List`
+        let all = list.filter(|_| true);
+        assert!(Rc::ptr_eq(
+            all.head.as_ref().unwrap(),
+            list.head.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn map_filter_long() {
+        let list = (0..100_000).fold(List::new(), |list, elem| list.prepend(elem));
+        let mapped = list.map(|elem| elem * 2);
+        assert_eq!(mapped.len(), 100_000);
+        let filtered = mapped.filter(|elem| elem % 4 == 0);
+        assert_eq!(filtered.len(), 50_000);
+        assert_eq!(filtered.head(), Some(&199_996));
+    }
+
+    #[test]
+    fn eq() {
+        let shared = List::new().prepend(3).prepend(2);
+        let a = shared.prepend(1);
+        let b = shared.prepend(1);
+        let c = shared.prepend(0);
+
+        assert!(a == b);
+        assert!(a != c);
+        assert!(a != shared);
+        assert!(List::<i32>::new() == List::new());
+
+        // equal, but not sharing anything
+        let copy = List::new().prepend(3).prepend(2).prepend(1);
+        assert!(a == copy);
+        assert!(copy != List::new().prepend(4).prepend(2).prepend(1));
+    }
+
+    #[test]
+    fn eq_shared_fast_path() {
+        use std::cell::Cell;
+
+        // counts how many times elements get compared
+        struct Counted<'a>(i32, &'a Cell<usize>);
+        impl PartialEq for Counted<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                self.1.set(self.1.get() + 1);
+                self.0 == other.0
+            }
+        }
+
+        let comparisons = Cell::new(0);
+        let shared = (0..1000).fold(List::new(), |list, elem| {
+            list.prepend(Counted(elem, &comparisons))
+        });
+        let a = shared
+            .prepend(Counted(1, &comparisons))
+            .prepend(Counted(2, &comparisons));
+        let b = shared
+            .prepend(Counted(1, &comparisons))
+            .prepend(Counted(2, &comparisons));
+
+        assert!(a == b);
+        // only the two unshared `Node`s were compared
+        assert_eq!(comparisons.get(), 2);
+    }
+
+    #[test]
+    fn shared_structure() {
+        let shared = List::new().prepend(3).prepend(2);
+        let list1 = shared.prepend(1);
+        let list2 = shared.prepend(5).prepend(4);
+        let copy = List::new().prepend(3).prepend(2).prepend(1);
+
+        assert!(list1.shares_structure(&list2));
+        assert_eq!(list1.shared_suffix_len(&list2), 2);
+        assert_eq!(list2.shared_suffix_len(&list1), 2);
+
+        // a `List` shares everything with itself and its tails
+        assert_eq!(list1.shared_suffix_len(&list1), 3);
+        assert_eq!(list1.shared_suffix_len(&list1.tail()), 2);
+
+        // equal elements are not enough, the `Node`s must be the same
+        assert!(!list1.shares_structure(&copy));
+        assert_eq!(list1.shared_suffix_len(&copy), 0);
+        assert!(!list1.shares_structure(&List::new()));
+    }
+
+    #[test]
+    fn suffixes() {
+        let list = List::new().prepend(3).prepend(2).prepend(1);
+
+        let suffixes: Vec<Vec<i32>> = list
+            .suffixes()
+            .map(|suffix| suffix.iter().copied().collect())
+            .collect();
+        assert_eq!(suffixes, vec![vec![1, 2, 3], vec![2, 3], vec![3], vec![]]);
+
+        // every non-empty suffix shares all of its `Node`s with the original
+        for suffix in list.suffixes() {
+            assert_eq!(suffix.shared_suffix_len(&list), suffix.len());
+        }
+
+        assert_eq!(List::<i32>::new().suffixes().count(), 1);
+    }
+
+    #[test]
+    fn strong_counts() {
+        let list = List::new();
+        assert_eq!(list.head_strong_count(), None);
+        assert_eq!(list.strong_counts().next(), None);
+
+        let list = list.prepend(3).prepend(2).prepend(1);
+        assert_eq!(list.head_strong_count(), Some(1));
+        assert_eq!(
+            list.strong_counts().collect::<Vec<_>>(),
+            vec![(0, 1), (1, 1), (2, 1)]
+        );
+
+        // a second `List` pointing at the second `Node`
+        let other = list.tail().prepend(4);
+        assert_eq!(
+            list.strong_counts().collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (2, 1)]
+        );
+        assert_eq!(
+            other.strong_counts().collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (2, 1)]
+        );
+
+        let tail = list.tail();
+        assert_eq!(tail.head_strong_count(), Some(3));
+
+        drop(other);
+        drop(tail);
+        assert_eq!(
+            list.strong_counts().collect::<Vec<_>>(),
+            vec![(0, 1), (1, 1), (2, 1)]
+        );
+    }
+
     #[test]
     fn iter() {
         let list = List::new().prepend(1).prepend(2).prepend(3);
@@ -134,4 +1326,249 @@ mod test {
         assert_eq!(iter.next(), Some(&1));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn fold_while() {
+        let list = List::new().prepend(1).prepend(2).prepend(3).prepend(4);
+
+        // sum until the running total would exceed 7: 4 + 3 = 7, adding 2 would overshoot
+        let sum = list.fold_while(0, |acc, &elem| {
+            if acc + elem > 7 {
+                ControlFlow::Break(acc)
+            } else {
+                ControlFlow::Continue(acc + elem)
+            }
+        });
+        assert_eq!(sum, 7);
+
+        // without breaking, it is a regular left fold
+        let sum = list.fold_while(0, |acc, &elem| ControlFlow::Continue(acc + elem));
+        assert_eq!(sum, 10);
+    }
+
+    #[test]
+    fn fold_rfold() {
+        let list = List::new().prepend(3).prepend(2).prepend(1);
+
+        let left = list.fold(String::new(), |acc, elem| format!("({}{})", acc, elem));
+        assert_eq!(left, "(((1)2)3)");
+        let right = list.rfold(String::new(), |elem, acc| format!("({}{})", elem, acc));
+        assert_eq!(right, "(1(2(3)))");
+
+        // a right fold with `prepend` rebuilds the same `List`
+        let copy = list.rfold(List::new(), |&elem, acc| acc.prepend(elem));
+        assert!(copy.iter().eq(list.iter()));
+
+        let long = (0..100_000u64).fold(List::new(), |list, elem| list.prepend(elem));
+        assert_eq!(
+            long.rfold(0, |elem, acc| acc + elem),
+            long.fold(0, |acc, elem| acc + elem)
+        );
+    }
+
+    #[test]
+    fn any_all_position() {
+        let empty = List::<i32>::new();
+        assert!(!empty.any(|_| true));
+        assert!(empty.all(|_| false));
+        assert_eq!(empty.position(|_| true), None);
+
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+        assert!(list.any(|&elem| elem == 2));
+        assert!(!list.any(|&elem| elem == 4));
+        assert!(list.all(|&elem| elem > 0));
+        assert!(!list.all(|&elem| elem > 1));
+        assert_eq!(list.position(|&elem| elem == 3), Some(0));
+        assert_eq!(list.position(|&elem| elem == 1), Some(2));
+        assert_eq!(list.position(|&elem| elem == 4), None);
+    }
+
+    #[test]
+    fn last_nth() {
+        let empty = List::<i32>::new();
+        assert_eq!(empty.last(), None);
+        assert_eq!(empty.nth(0), None);
+
+        let list = List::new().prepend(3).prepend(2).prepend(1);
+        assert_eq!(list.last(), Some(&3));
+        assert_eq!(list.nth(0), Some(&1));
+        assert_eq!(list.nth(2), Some(&3));
+        assert_eq!(list.nth(3), None);
+        assert_eq!(list.nth(usize::MAX), None);
+    }
+
+    #[test]
+    fn starts_ends_with() {
+        let list = List::new().prepend(3).prepend(2).prepend(1);
+        let empty = List::new();
+
+        assert!(list.starts_with(&empty));
+        assert!(list.starts_with(&List::new().prepend(2).prepend(1)));
+        assert!(list.starts_with(&list));
+        assert!(!list.starts_with(&List::new().prepend(2)));
+        assert!(!list.starts_with(&list.prepend(0)));
+
+        assert!(list.ends_with(&empty));
+        assert!(empty.ends_with(&empty));
+        assert!(!empty.ends_with(&list));
+        // shared tails and unrelated but equal `List`s both count
+        assert!(list.ends_with(&list.tail()));
+        assert!(list.ends_with(&list.drop(2)));
+        assert!(list.ends_with(&List::new().prepend(3).prepend(2)));
+        assert!(!list.ends_with(&List::new().prepend(2)));
+        assert!(!list.ends_with(&list.prepend(0)));
+    }
+
+    #[test]
+    fn hash() {
+        fn hash_of(list: &List<i32>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            list.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let list = List::new().prepend(3).prepend(2).prepend(1);
+        let shared = list.tail().prepend(1);
+        let separate = List::new().prepend(3).prepend(2).prepend(1);
+        assert_eq!(hash_of(&list), hash_of(&shared));
+        assert_eq!(hash_of(&list), hash_of(&separate));
+        assert_eq!(hash_of(&list.tail()), hash_of(&separate.tail()));
+
+        // usable as a memoization key
+        let mut memo = HashMap::new();
+        memo.insert(list.tail(), list.tail().fold(0, |acc, elem| acc + elem));
+        assert_eq!(memo.get(&separate.tail()), Some(&5));
+        assert_eq!(memo.get(&separate), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(List::<i32>::new().to_string(), "");
+        let list = List::new().prepend(3).prepend(2).prepend(1);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3");
+    }
+
+    #[test]
+    fn fmt_with_sharing() {
+        // the lists from the diagram at the top of the module
+        let list2 = List::new().prepend('D').prepend('C').prepend('B');
+        let list1 = list2.prepend('A');
+        let list3 = list2.prepend('X');
+        let empty = List::new();
+
+        let lists = [
+            ("list1", &list1),
+            ("list2", &list2),
+            ("list3", &list3),
+            ("empty", &empty),
+        ];
+        assert_eq!(
+            List::fmt_with_sharing(&lists).to_string(),
+            "list1 -> A -> B -> C -> D\n\
+             list2 --+ list1[1]\n\
+             list3 -> X --+ list1[1]\n\
+             empty (empty)"
+        );
+
+        // a `List` printed first owns the shared `Node`s
+        let lists = [("list3", &list3), ("list1", &list1)];
+        assert_eq!(
+            List::fmt_with_sharing(&lists).to_string(),
+            "list3 -> X -> B -> C -> D\n\
+             list1 -> A --+ list3[1]"
+        );
+    }
+
+    #[test]
+    fn unique_node_count() {
+        let list2 = List::new().prepend('D').prepend('C').prepend('B');
+        let list1 = list2.prepend('A');
+        let list3 = list2.prepend('X');
+
+        let count = List::unique_node_count(&[&list1, &list2, &list3]);
+        assert_eq!(
+            count,
+            NodeCount {
+                unique: 5,
+                total: 11
+            }
+        );
+        assert_eq!(count.saved(), 6);
+
+        // the same `List` twice only counts its `Node`s once
+        let count = List::unique_node_count(&[&list1, &list1]);
+        assert_eq!(
+            count,
+            NodeCount {
+                unique: 4,
+                total: 8
+            }
+        );
+
+        // equal, but not shared
+        let copy = List::from(&['B', 'C', 'D'][..]);
+        assert_eq!(List::unique_node_count(&[&list2, &copy]).saved(), 0);
+        assert_eq!(List::<i32>::unique_node_count(&[]).total, 0);
+    }
+
+    #[test]
+    fn contains_find() {
+        let empty = List::<i32>::new();
+        assert!(!empty.contains(&1));
+        assert_eq!(empty.find(|_| true), None);
+
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+        assert!(list.contains(&2));
+        assert!(!list.contains(&4));
+        assert_eq!(list.find(|&elem| elem < 3), Some(&2));
+        assert_eq!(list.find(|&elem| elem > 3), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let list = List::new().prepend(3).prepend(2).prepend(1);
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[1,2,3]");
+
+        let back: List<i32> = serde_json::from_str(&json).unwrap();
+        assert!(back == list);
+        assert_eq!(back.len(), 3);
+        assert!(!back.shares_structure(&list));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_shared() {
+        use super::SharedLists;
+
+        let base = List::new().prepend(3).prepend(2);
+        let a = base.prepend(1);
+        let b = base.tail().prepend(4);
+        let lists = SharedLists(vec![a, b, base, List::new()]);
+
+        let json = serde_json::to_string(&lists).unwrap();
+        // 3 and 2 are written once, even though they are in three `List`s
+        assert_eq!(
+            json,
+            r#"{"nodes":[[3,null],[2,0],[1,1],[4,0]],"heads":[2,3,1,null]}"#
+        );
+
+        let SharedLists(back): SharedLists<i32> = serde_json::from_str(&json).unwrap();
+        assert!(back[0].iter().eq([1, 2, 3].iter()));
+        assert!(back[1].iter().eq([4, 3].iter()));
+        assert!(back[2].iter().eq([2, 3].iter()));
+        assert!(back[3].is_empty());
+        assert_eq!(back[0].len(), 3);
+        // the sharing is restored
+        assert_eq!(back[0].shared_suffix_len(&back[2]), 2);
+        assert_eq!(back[0].shared_suffix_len(&back[1]), 1);
+        assert_eq!(back[2].head_strong_count(), Some(2));
+
+        // `next` has to point to an earlier `Node`
+        let invalid = r#"{"nodes":[[1,0]],"heads":[0]}"#;
+        assert!(serde_json::from_str::<SharedLists<i32>>(invalid).is_err());
+        let invalid = r#"{"nodes":[[1,null]],"heads":[1]}"#;
+        assert!(serde_json::from_str::<SharedLists<i32>>(invalid).is_err());
+    }
 }
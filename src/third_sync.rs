@@ -0,0 +1,403 @@
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+/// The same persistent `List` as in `third`, but every `Rc` is replaced with `Arc`.
+/// `Arc` uses atomic operations for reference counting, so it is a bit slower, but because of that
+/// it is `Send` and `Sync` (as long as `T` is), which means a `List` can be shared between threads.
+pub struct List<T> {
+    head: Link<T>,
+}
+
+type Link<T> = Option<Arc<Node<T>>>;
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+    // length of the sublist starting at this `Node`, same as in `third`
+    len: usize,
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List { head: None }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// Return a new `List` that has the provided element added to the front, the original `List` is still usable
+    pub fn prepend(&self, elem: T) -> List<T> {
+        List {
+            head: Some(Arc::new(Node {
+                elem,
+                next: self.head.clone(),
+                len: self.len() + 1,
+            })),
+        }
+    }
+
+    /// Return a `List` that contains everything but the first element of this one
+    pub fn tail(&self) -> List<T> {
+        List {
+            head: self.head.as_ref().and_then(|node| node.next.clone()),
+        }
+    }
+
+    /// Returns a reference pointing to the first element
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    /// Returns the number of elements in O(1), by reading the length stored in the first `Node`
+    pub fn len(&self) -> usize {
+        self.head.as_ref().map_or(0, |node| node.len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Left fold that can stop early, same as in `third`. The `Node`s are walked through plain references,
+    /// so none of the atomic reference counts are touched, unlike when walking with `tail`, which clones an `Arc`
+    /// for every step.
+    pub fn fold_while<B>(&self, init: B, mut f: impl FnMut(B, &T) -> ControlFlow<B, B>) -> B {
+        let mut acc = init;
+        let mut cur = self.head.as_deref();
+        while let Some(node) = cur {
+            match f(acc, &node.elem) {
+                ControlFlow::Continue(next) => acc = next,
+                ControlFlow::Break(result) => return result,
+            }
+            cur = node.next.as_deref();
+        }
+        acc
+    }
+
+    /// Left fold, combining the elements front to back: `f(f(f(init, a), b), c)`
+    pub fn fold<B>(&self, init: B, mut f: impl FnMut(B, &T) -> B) -> B {
+        self.fold_while(init, |acc, elem| ControlFlow::Continue(f(acc, elem)))
+    }
+
+    /// Returns whether any element matches the predicate, stopping at the first match
+    pub fn any(&self, mut f: impl FnMut(&T) -> bool) -> bool {
+        self.fold_while(false, |_, elem| {
+            if f(elem) {
+                ControlFlow::Break(true)
+            } else {
+                ControlFlow::Continue(false)
+            }
+        })
+    }
+
+    /// Returns whether all elements match the predicate, stopping at the first mismatch
+    pub fn all(&self, mut f: impl FnMut(&T) -> bool) -> bool {
+        !self.any(|elem| !f(elem))
+    }
+
+    /// Returns the index of the first element matching the predicate
+    pub fn position(&self, mut f: impl FnMut(&T) -> bool) -> Option<usize> {
+        let mut cur = self.head.as_deref();
+        let mut index = 0;
+        while let Some(node) = cur {
+            if f(&node.elem) {
+                return Some(index);
+            }
+            index += 1;
+            cur = node.next.as_deref();
+        }
+        None
+    }
+}
+
+impl<T> Clone for List<T> {
+    /// Cloning only bumps the reference count of the first `Node`, this is how a `List` is handed to another thread
+    fn clone(&self) -> Self {
+        List {
+            head: self.head.clone(),
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            // only the owner of the last reference gets the `Node` back; `try_unwrap` is not enough here,
+            // as two threads dropping at once could both fail and leave the rest to the recursive `Drop`,
+            // while `into_inner` returns `Some` to exactly one of them, which then keeps freeing the chain
+            if let Some(mut node) = Arc::into_inner(node) {
+                head = node.next.take();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+/// `&List` can be turned into a rayon `ParallelIterator`, so `list.par_iter()` works.
+/// This only makes sense here, as the `Rc`s in `third` cannot leave their thread.
+#[cfg(feature = "rayon")]
+impl<'a, T: Send + Sync + 'a> rayon::iter::IntoParallelIterator for &'a List<T> {
+    type Iter = ParIter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter {
+            head: self.head.as_deref(),
+            len: self.len(),
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+pub struct ParIter<'a, T> {
+    head: Option<&'a Node<T>>,
+    len: usize,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: Send + Sync + 'a> rayon::iter::ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        rayon::iter::plumbing::bridge_unindexed(self, consumer)
+    }
+}
+
+/// The `ParIter` is its own producer: it is a sublist of `len` `Node`s starting at `head`.
+/// A singly linked `List` cannot jump to its middle, so each `split` walks half of its range. Splitting all
+/// the way down would cost O(n log n), but rayon only splits about once per thread, and again when work is
+/// stolen, so for p threads the walking adds up to O(n log p) on top of the O(n) of visiting the elements.
+/// The stored lengths make `into_par_iter` itself O(1), as nothing has to be counted up front.
+#[cfg(feature = "rayon")]
+impl<'a, T: Send + Sync + 'a> rayon::iter::plumbing::UnindexedProducer for ParIter<'a, T> {
+    type Item = &'a T;
+
+    fn split(self) -> (Self, Option<Self>) {
+        if self.len < 2 {
+            return (self, None);
+        }
+        // walk to the middle, the second half starts there and the first half just stops early
+        let mid = self.len / 2;
+        let mut cur = self.head;
+        for _ in 0..mid {
+            cur = cur.and_then(|node| node.next.as_deref());
+        }
+        (
+            ParIter {
+                head: self.head,
+                len: mid,
+            },
+            Some(ParIter {
+                head: cur,
+                len: self.len - mid,
+            }),
+        )
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+    where
+        F: rayon::iter::plumbing::Folder<Self::Item>,
+    {
+        folder.consume_iter(Iter { next: self.head }.take(self.len))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::List;
+    use std::ops::ControlFlow;
+    use std::sync::Barrier;
+    use std::thread;
+
+    #[test]
+    fn basics() {
+        let list = List::new();
+        assert_eq!(list.head(), None);
+
+        let list = list.prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.head(), Some(&3));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&2));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&1));
+
+        let list = list.tail();
+        assert_eq!(list.head(), None);
+
+        let list = list.tail();
+        assert_eq!(list.head(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn len() {
+        let list = List::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        let list = list.prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.len(), 3);
+        assert!(!list.is_empty());
+        // a shared tail keeps its own length
+        assert_eq!(list.tail().len(), 2);
+        assert_eq!(list.tail().prepend(4).prepend(5).len(), 4);
+        assert_eq!(list.tail().tail().tail().len(), 0);
+    }
+
+    #[test]
+    fn send_sync() {
+        fn is_send<T: Send>() {}
+        fn is_sync<T: Sync>() {}
+
+        is_send::<List<i32>>();
+        is_sync::<List<i32>>();
+    }
+
+    #[test]
+    fn share_across_threads() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let list = list.clone();
+                // every thread prepends to the same shared suffix
+                thread::spawn(move || {
+                    let list = list.prepend(i);
+                    list.iter().sum::<i32>()
+                })
+            })
+            .collect();
+
+        let sums: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(sums, vec![6, 7, 8, 9]);
+
+        // the original is untouched
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn concurrent_drop() {
+        // every thread drops its own `List` sharing a long suffix at the same time, whichever of them
+        // releases a `Node` last has to keep freeing iteratively, or it overflows its stack
+        for _ in 0..4 {
+            let suffix = (0..100_000).fold(List::new(), |list, i| list.prepend(i));
+            let barrier = Barrier::new(4);
+            thread::scope(|s| {
+                for i in 0..4 {
+                    let list = suffix.prepend(i);
+                    let barrier = &barrier;
+                    s.spawn(move || {
+                        barrier.wait();
+                        drop(list);
+                    });
+                }
+                drop(suffix);
+            });
+        }
+    }
+
+    #[test]
+    fn share_by_reference() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+
+        // `Sync` means `&List` can be used from scoped threads without cloning
+        thread::scope(|s| {
+            s.spawn(|| assert_eq!(list.head(), Some(&3)));
+            s.spawn(|| assert_eq!(list.tail().head(), Some(&2)));
+        });
+    }
+
+    #[test]
+    fn fold_while() {
+        let list = List::new().prepend(1).prepend(2).prepend(3).prepend(4);
+
+        let sum = list.fold_while(0, |acc, &elem| {
+            if acc + elem > 7 {
+                ControlFlow::Break(acc)
+            } else {
+                ControlFlow::Continue(acc + elem)
+            }
+        });
+        assert_eq!(sum, 7);
+        assert_eq!(list.fold(0, |acc, &elem| acc + elem), 10);
+    }
+
+    #[test]
+    fn any_all_position() {
+        let empty = List::<i32>::new();
+        assert!(!empty.any(|_| true));
+        assert!(empty.all(|_| false));
+        assert_eq!(empty.position(|_| true), None);
+
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+        assert!(list.any(|&elem| elem == 2));
+        assert!(!list.any(|&elem| elem == 4));
+        assert!(list.all(|&elem| elem > 0));
+        assert!(!list.all(|&elem| elem > 1));
+        assert_eq!(list.position(|&elem| elem == 3), Some(0));
+        assert_eq!(list.position(|&elem| elem == 1), Some(2));
+        assert_eq!(list.position(|&elem| elem == 4), None);
+
+        // a shared tail answers the same as the list it came from, shifted by one
+        let tail = list.tail();
+        assert_eq!(tail.position(|&elem| elem == 1), Some(1));
+        assert!(!tail.any(|&elem| elem == 3));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter() {
+        use rayon::prelude::*;
+
+        let list = (0..10_000u64).fold(List::new(), |list, i| list.prepend(i));
+        assert_eq!(list.par_iter().sum::<u64>(), (0..10_000).sum::<u64>());
+        assert_eq!(list.par_iter().count(), 10_000);
+        assert_eq!(list.par_iter().filter(|&&i| i % 2 == 0).count(), 5_000);
+
+        // the order is kept when collecting
+        let collected: Vec<_> = list.par_iter().copied().collect();
+        assert_eq!(collected, list.iter().copied().collect::<Vec<_>>());
+
+        assert_eq!(List::<u64>::new().par_iter().count(), 0);
+        assert_eq!(list.tail().par_iter().max(), Some(&9_998));
+    }
+}